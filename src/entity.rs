@@ -9,6 +9,7 @@
 //! All variants will be serialized in `snake_case`.
 
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use strum_macros::*;
 
 /// Supported entity types.
@@ -24,6 +25,10 @@ pub enum EntityType {
     Climate,
     Cover,
     Light,
+    /// The deprecated `mediaplayer` spelling is only accepted on input, for compatibility with older
+    /// integration drivers. This crate has always serialized `media_player`: there is no crate version
+    /// in which the `mediaplayer` spelling was deprecated.
+    #[serde(alias = "mediaplayer")]
     MediaPlayer,
     Sensor,
     /// Internal entity only at the moment
@@ -31,7 +36,24 @@ pub enum EntityType {
     /// Internal entity only at the moment
     Macro,
     Remote,
+    /// The deprecated `iremitter` spelling is only accepted on input, for compatibility with older
+    /// integration drivers. This crate has always serialized `ir_emitter`: there is no crate version
+    /// in which the `iremitter` spelling was deprecated.
+    #[serde(alias = "iremitter")]
     IrEmitter,
+    Lock,
+    Fan,
+    Vacuum,
+    AlarmControlPanel,
+    Select,
+}
+
+impl EntityType {
+    /// Returns true if the entity type is only used internally in the remote and not provided by
+    /// integration drivers.
+    pub fn is_internal(&self) -> bool {
+        matches!(self, EntityType::Activity | EntityType::Macro)
+    }
 }
 
 /// Button features.
@@ -101,6 +123,22 @@ pub enum SwitchDeviceClass {
 #[strum(serialize_all = "snake_case")]
 pub enum SwitchOptionField {
     Readable,
+    /// State of the switch when power is restored. See [`PowerOnBehavior`] for available values.
+    PowerOnBehavior,
+}
+
+/// Switch state when power is restored, e.g. of a smart plug.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum PowerOnBehavior {
+    /// Always switch on.
+    AlwaysOn,
+    /// Always switch off.
+    AlwaysOff,
+    /// Restore the last state before the power loss.
+    RestoreLast,
 }
 
 /// Switch entity attributes.
@@ -123,8 +161,13 @@ pub enum ClimateFeature {
     Cool,
     CurrentTemperature,
     TargetTemperature,
-    //TargetTemperatureRange Not yet implemented
-    //Fan Not yet implemented
+    /// Adjustable airflow direction, e.g. of an air conditioning unit.
+    SwingMode,
+    /// Adjustable fan mode, e.g. `auto`, `low`, `medium`, `high`.
+    FanMode,
+    /// Dual setpoints with the `target_temperature_low` and `target_temperature_high` attributes,
+    /// e.g. for heating and cooling.
+    TargetTemperatureRange,
 }
 
 /// Climate entity option fields.
@@ -143,9 +186,60 @@ pub enum ClimateOptionField {
     MaxTemperature,
     /// Minimum temperature to show in the UI for the target temperature range.
     MinTemperature,
+    /// List of supported HVAC modes. See [`ClimateHvacMode`] for available modes.
+    /// If not specified, all modes of the supported features are shown in the UI.
+    HvacModes,
+    /// List of supported swing modes, e.g. `off`, `both`, `vertical`, `horizontal`.
+    /// Requires the `swing_mode` feature.
+    SwingModes,
     //FanModes Not yet implemented
 }
 
+impl ClimateOptionField {
+    /// Get the supported HVAC modes of the `hvac_modes` option from an entity options map.
+    ///
+    /// Unknown modes are ignored. Returns an empty list if the option is not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use uc_api::{ClimateHvacMode, ClimateOptionField};
+    /// let options = json!({ "hvac_modes": ["OFF", "HEAT", "FOOBAR"] });
+    /// assert_eq!(
+    ///     vec![ClimateHvacMode::Off, ClimateHvacMode::Heat],
+    ///     ClimateOptionField::hvac_modes(options.as_object().unwrap())
+    /// );
+    /// ```
+    pub fn hvac_modes(options: &Map<String, Value>) -> Vec<ClimateHvacMode> {
+        options
+            .get(ClimateOptionField::HvacModes.as_ref())
+            .and_then(|v| v.as_array())
+            .map(|modes| {
+                modes
+                    .iter()
+                    .filter_map(|mode| mode.as_str())
+                    .filter_map(|mode| mode.parse().ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Climate HVAC modes, used in the `hvac_mode` command and the `hvac_modes` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum ClimateHvacMode {
+    Off,
+    Heat,
+    Cool,
+    HeatCool,
+    Fan,
+    Auto,
+}
+
 /// Climate entity commands.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -156,8 +250,15 @@ pub enum ClimateCommand {
     Off,
     HvacMode,
     TargetTemperature,
-    // TargetTemperatureRange,
-    // FanMode,
+    /// Set the swing mode. See [`SetSwingModeParams`](crate::SetSwingModeParams) for the command
+    /// parameters.
+    SetSwingMode,
+    /// Set the fan mode. See [`SetFanModeParams`](crate::SetFanModeParams) for the command
+    /// parameters.
+    FanMode,
+    /// Set the target temperature range. See
+    /// [`SetTemperatureRangeParams`](crate::SetTemperatureRangeParams) for the command parameters.
+    TargetTemperatureRange,
 }
 
 /// Climate entity attributes.
@@ -171,7 +272,12 @@ pub enum ClimateAttribute {
     TargetTemperature,
     TargetTemperatureHigh,
     TargetTemperatureLow,
+    /// Current fan mode, one of the values in the `fan_mode_list` attribute.
     FanMode,
+    /// List of available fan modes. Requires the `fan_mode` feature.
+    FanModeList,
+    /// Current swing mode, one of the values in the `swing_modes` option.
+    SwingMode,
 }
 
 /// Cover entity features.
@@ -184,9 +290,12 @@ pub enum CoverFeature {
     Close,
     Stop,
     Position,
-    // Tilt,
-    // TiltStop,
-    // TiltPosition,
+    /// The cover slats can be tilted, e.g. of a venetian blind.
+    Tilt,
+    /// A tilt movement can be stopped.
+    TiltStop,
+    /// The cover supports setting and reporting the tilt position.
+    TiltPosition,
 }
 
 /// Cover entity commands.
@@ -198,7 +307,16 @@ pub enum CoverCommand {
     Open,
     Close,
     Stop,
+    /// Move to a position. See [`SetPositionParams`](crate::SetPositionParams) for the command
+    /// parameters.
     Position,
+    /// Tilt the cover slats to a position. See [`SetPositionParams`](crate::SetPositionParams)
+    /// for the command parameters.
+    Tilt,
+    TiltStop,
+    /// Set the tilt position. See [`SetTiltPositionParams`](crate::SetTiltPositionParams) for the
+    /// command parameters.
+    TiltPosition,
 }
 
 /// Cover entity device classes.
@@ -239,6 +357,8 @@ pub enum LightFeature {
     Dim,
     Color,
     ColorTemperature,
+    /// Named lighting effects can be selected.
+    Effect,
 }
 
 /// Light entity commands.
@@ -250,6 +370,9 @@ pub enum LightCommand {
     On,
     Off,
     Toggle,
+    /// Activate a lighting effect. See [`LightEffectParams`](crate::LightEffectParams) for the
+    /// command parameters.
+    Effect,
 }
 
 /// Light entity option fields.
@@ -272,6 +395,10 @@ pub enum LightAttribute {
     Saturation,
     Brightness,
     ColorTemperature,
+    /// Name of the active lighting effect.
+    Effect,
+    /// List of available lighting effect names.
+    EffectList,
 }
 
 /// Media player entity features.
@@ -339,6 +466,13 @@ pub enum MediaPlayerFeature {
     Record,
     /// The player supports a settings menu.
     Settings,
+    /// The player reports the current track number and total track count, e.g. of a disc or
+    /// playlist, with the `media_track_number` and `media_track_count` attributes.
+    TrackInfo,
+    /// The player has a playlist which can be navigated and modified.
+    Playlist,
+    /// The player exposes a playback queue with the `queue_position` and `queue_size` attributes.
+    Queue,
 }
 
 /// Media player entity commands.
@@ -424,6 +558,50 @@ pub enum MediaPlayerCommand {
     Subtitle,
     /// Settings menu
     Settings,
+    /// Play the previous item in the playlist.
+    PlaylistPrevious,
+    /// Play the next item in the playlist.
+    PlaylistNext,
+    /// Add media to the playlist.
+    AddToPlaylist,
+    /// Remove all items from the playlist.
+    ClearPlaylist,
+}
+
+impl MediaPlayerCommand {
+    /// Returns true for menu navigation commands: directional pad, back, home and menus.
+    pub fn is_navigation_command(&self) -> bool {
+        matches!(
+            self,
+            MediaPlayerCommand::CursorUp
+                | MediaPlayerCommand::CursorDown
+                | MediaPlayerCommand::CursorLeft
+                | MediaPlayerCommand::CursorRight
+                | MediaPlayerCommand::CursorEnter
+                | MediaPlayerCommand::Back
+                | MediaPlayerCommand::Home
+                | MediaPlayerCommand::Menu
+                | MediaPlayerCommand::Guide
+                | MediaPlayerCommand::Info
+                | MediaPlayerCommand::ContextMenu
+        )
+    }
+
+    /// Returns true for media playback control commands.
+    pub fn is_playback_command(&self) -> bool {
+        matches!(
+            self,
+            MediaPlayerCommand::PlayPause
+                | MediaPlayerCommand::Stop
+                | MediaPlayerCommand::Next
+                | MediaPlayerCommand::Previous
+                | MediaPlayerCommand::FastForward
+                | MediaPlayerCommand::Rewind
+                | MediaPlayerCommand::Seek
+                | MediaPlayerCommand::PlaylistPrevious
+                | MediaPlayerCommand::PlaylistNext
+        )
+    }
 }
 
 /// Media player entity device classes.
@@ -442,6 +620,16 @@ pub enum MediaPlayerDeviceClass {
     StreamingBox,
     /// Television device.
     TV,
+    /// Record player.
+    Turntable,
+    /// Compact disc player.
+    CdPlayer,
+    /// Audio cassette deck.
+    CassetteDeck,
+    /// AM / FM radio tuner.
+    RadioTuner,
+    /// MiniDisc player or recorder.
+    MiniDisc,
 }
 
 /// Media player entity option fields.
@@ -456,6 +644,47 @@ pub enum MediaPlayerOptionField {
     VolumeSteps,
 }
 
+impl MediaPlayerOptionField {
+    /// Get the `simple_commands` option from an entity options map.
+    ///
+    /// Returns an empty list if the option is not set or not a list of strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use uc_api::MediaPlayerOptionField;
+    /// let options = json!({ "simple_commands": ["INPUT_1", "INPUT_2"], "volume_steps": 50 });
+    /// let options = options.as_object().unwrap();
+    /// assert_eq!(vec!["INPUT_1", "INPUT_2"], MediaPlayerOptionField::simple_commands(options));
+    /// assert_eq!(Some(50), MediaPlayerOptionField::volume_steps(options));
+    /// ```
+    pub fn simple_commands(options: &Map<String, Value>) -> Vec<String> {
+        options
+            .get(MediaPlayerOptionField::SimpleCommands.as_ref())
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    /// Create the `simple_commands` option value for an entity options map.
+    pub fn simple_commands_value<S: AsRef<str>>(commands: &[S]) -> Value {
+        Value::Array(
+            commands
+                .iter()
+                .map(|c| Value::String(c.as_ref().to_string()))
+                .collect(),
+        )
+    }
+
+    /// Get the `volume_steps` option from an entity options map.
+    pub fn volume_steps(options: &Map<String, Value>) -> Option<u32> {
+        options
+            .get(MediaPlayerOptionField::VolumeSteps.as_ref())
+            .and_then(|v| v.as_u64())
+            .and_then(|v| u32::try_from(v).ok())
+    }
+}
+
 /// Media player media types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -467,6 +696,10 @@ pub enum MediaType {
     Tvshow,
     Movie,
     Video,
+    Podcast,
+    Audiobook,
+    LiveStream,
+    Game,
 }
 
 /// Media player repeat modes.
@@ -489,7 +722,13 @@ pub enum MediaPlayerAttribute {
     State,
     Volume,
     Muted,
+    /// Current media playback position in seconds.
     MediaPosition,
+    /// Timestamp when `media_position` was last updated, as ISO 8601 date-time in UTC.
+    ///
+    /// Allows clients to calculate the current position during playback without polling:
+    /// `media_position + (now - media_position_updated_at)`.
+    MediaPositionUpdatedAt,
     MediaDuration,
     MediaTitle,
     MediaArtist,
@@ -505,6 +744,24 @@ pub enum MediaPlayerAttribute {
     SourceList,
     SoundMode,
     SoundModeList,
+    /// Current track number on the disc or in the playlist, starting at 1.
+    /// Requires the `track_info` feature.
+    MediaTrackNumber,
+    /// Total number of tracks on the disc or in the playlist.
+    /// Requires the `track_info` feature.
+    MediaTrackCount,
+    /// Title of the current playlist. Requires the `playlist` feature.
+    PlaylistTitle,
+    /// Number of items in the current playlist. Requires the `playlist` feature.
+    PlaylistItemCount,
+    /// Position of the current item in the playlist, starting at 1.
+    /// Requires the `playlist` feature.
+    PlaylistPosition,
+    /// Position of the current item in the playback queue, starting at 1.
+    /// Requires the `queue` feature.
+    QueuePosition,
+    /// Number of items in the playback queue. Requires the `queue` feature.
+    QueueSize,
 }
 
 /// Sensor entity option fields.
@@ -549,6 +806,132 @@ pub enum SensorDeviceClass {
     Temperature,
     /// Voltage in volt
     Voltage,
+    /// Atmospheric pressure in hPa
+    Pressure,
+    /// Illuminance in lx
+    Illuminance,
+    /// Carbon dioxide concentration in ppm
+    CarbonDioxide,
+    /// Carbon monoxide concentration in ppm
+    CarbonMonoxide,
+    /// Particulate matter with a diameter of 2.5 µm or less in µg/m³
+    Pm25,
+    /// Particulate matter with a diameter of 10 µm or less in µg/m³
+    Pm10,
+    /// Sound level in dB
+    SoundLevel,
+    /// Nitrogen dioxide concentration in µg/m³
+    NitrogenDioxide,
+    /// Ozone concentration in µg/m³
+    Ozone,
+}
+
+/// Device class of an entity, typed according to the entity type.
+///
+/// The device class is transmitted as a plain string in the entity definition, this enum provides
+/// the typed variants for the entity types supporting a device class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityDeviceClass {
+    Switch(SwitchDeviceClass),
+    Cover(CoverDeviceClass),
+    MediaPlayer(MediaPlayerDeviceClass),
+    Sensor(SensorDeviceClass),
+    Lock(LockDeviceClass),
+    Vacuum(VacuumDeviceClass),
+}
+
+impl EntityDeviceClass {
+    /// Parse a device class string for the given entity type.
+    ///
+    /// Returns `None` if the entity type doesn't support device classes or if the value is not a
+    /// valid device class of the entity type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uc_api::{CoverDeviceClass, EntityDeviceClass, EntityType};
+    /// assert_eq!(
+    ///     Some(EntityDeviceClass::Cover(CoverDeviceClass::Garage)),
+    ///     EntityDeviceClass::from_entity_type(EntityType::Cover, "garage")
+    /// );
+    /// assert_eq!(None, EntityDeviceClass::from_entity_type(EntityType::Switch, "garage"));
+    /// ```
+    pub fn from_entity_type(entity_type: EntityType, device_class: &str) -> Option<Self> {
+        match entity_type {
+            EntityType::Switch => device_class.parse().ok().map(Self::Switch),
+            EntityType::Cover => device_class.parse().ok().map(Self::Cover),
+            EntityType::MediaPlayer => device_class.parse().ok().map(Self::MediaPlayer),
+            EntityType::Sensor => device_class.parse().ok().map(Self::Sensor),
+            EntityType::Lock => device_class.parse().ok().map(Self::Lock),
+            EntityType::Vacuum => device_class.parse().ok().map(Self::Vacuum),
+            _ => None,
+        }
+    }
+}
+
+impl AsRef<str> for EntityDeviceClass {
+    fn as_ref(&self) -> &str {
+        match self {
+            EntityDeviceClass::Switch(v) => v.as_ref(),
+            EntityDeviceClass::Cover(v) => v.as_ref(),
+            EntityDeviceClass::MediaPlayer(v) => v.as_ref(),
+            EntityDeviceClass::Sensor(v) => v.as_ref(),
+            EntityDeviceClass::Lock(v) => v.as_ref(),
+            EntityDeviceClass::Vacuum(v) => v.as_ref(),
+        }
+    }
+}
+
+impl SensorDeviceClass {
+    /// Get the default unit of measurement of the device class.
+    ///
+    /// Returns `None` for a `Custom` sensor, which requires the `custom_unit` option.
+    pub fn default_unit(&self) -> Option<&'static str> {
+        match self {
+            SensorDeviceClass::Custom => None,
+            SensorDeviceClass::Battery => Some("%"),
+            SensorDeviceClass::Current => Some("A"),
+            SensorDeviceClass::Energy => Some("kWh"),
+            SensorDeviceClass::Humidity => Some("%"),
+            SensorDeviceClass::Power => Some("W"),
+            SensorDeviceClass::Temperature => Some("°C"),
+            SensorDeviceClass::Voltage => Some("V"),
+            SensorDeviceClass::Pressure => Some("hPa"),
+            SensorDeviceClass::Illuminance => Some("lx"),
+            SensorDeviceClass::CarbonDioxide | SensorDeviceClass::CarbonMonoxide => Some("ppm"),
+            SensorDeviceClass::Pm25
+            | SensorDeviceClass::Pm10
+            | SensorDeviceClass::NitrogenDioxide
+            | SensorDeviceClass::Ozone => Some("µg/m³"),
+            SensorDeviceClass::SoundLevel => Some("dB"),
+        }
+    }
+
+    /// Get the unit label to display for the device class.
+    ///
+    /// For a `Temperature` sensor the `native_unit` option is respected: a Fahrenheit unit
+    /// (`°F`, `F` or `FAHRENHEIT`) returns `°F`, all other values the default unit. An empty string
+    /// is returned for a `Custom` sensor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uc_api::SensorDeviceClass;
+    /// assert_eq!("°F", SensorDeviceClass::Temperature.to_display_unit(Some("FAHRENHEIT")));
+    /// assert_eq!("°C", SensorDeviceClass::Temperature.to_display_unit(None));
+    /// assert_eq!("V", SensorDeviceClass::Voltage.to_display_unit(None));
+    /// ```
+    pub fn to_display_unit(&self, native_unit: Option<&str>) -> &'static str {
+        if let (SensorDeviceClass::Temperature, Some(unit)) = (self, native_unit) {
+            if unit == "°F"
+                || unit.eq_ignore_ascii_case("F")
+                || unit.eq_ignore_ascii_case("FAHRENHEIT")
+            {
+                return "°F";
+            }
+        }
+        self.default_unit().unwrap_or_default()
+    }
 }
 
 /// Sensor entity attributes.
@@ -619,9 +1002,337 @@ pub enum IrEmitterAttribute {
     State,
 }
 
+/// Lock entity features.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum LockFeature {
+    /// The lock can be locked.
+    Lock,
+    /// The lock can be unlocked.
+    Unlock,
+    /// The door can be opened, e.g. by pulling the latch of a door lock.
+    Open,
+    /// The lock has a latch which can be held open.
+    Latch,
+}
+
+/// Lock entity commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum LockCommand {
+    Lock,
+    Unlock,
+    Open,
+}
+
+/// Lock entity device classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum LockDeviceClass {
+    /// Deadbolt lock.
+    Deadbolt,
+    /// Door lock with a latch.
+    Doorlock,
+    /// Gateway or bridge controlling one or more locks.
+    Gateway,
+}
+
+/// Lock entity attributes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum LockAttribute {
+    State,
+    /// The lock is locked.
+    IsLocked,
+    /// The lock is jammed and couldn't reach the requested state.
+    IsJammed,
+    /// Battery level in percent: 0-100.
+    BatteryLevel,
+}
+
+/// Fan entity features.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum FanFeature {
+    OnOff,
+    Toggle,
+    /// Adjustable fan speed.
+    Speed,
+    /// Adjustable rotation direction, e.g. of a ceiling fan.
+    Direction,
+    /// The fan supports oscillation.
+    Oscillation,
+    /// Preset modes can be selected, e.g. `auto`, `sleep`.
+    PresetMode,
+}
+
+/// Fan entity commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum FanCommand {
+    On,
+    Off,
+    Toggle,
+    /// Set the fan speed. See [`SetFanSpeedParams`](crate::SetFanSpeedParams) for the command
+    /// parameters.
+    Speed,
+    Oscillation,
+    Direction,
+    SetPresetMode,
+}
+
+/// Fan entity option fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum FanOptionField {
+    /// Number of supported speed steps. The speed value of 0-100 is mapped to the speed steps.
+    SpeedSteps,
+}
+
+/// Fan entity attributes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum FanAttribute {
+    State,
+    /// Fan speed in percent: 0-100.
+    Speed,
+    /// Rotation direction: `forward`, `reverse`.
+    Direction,
+    /// The fan is oscillating.
+    Oscillating,
+    /// Current preset mode, one of the values in the `preset_mode_list` attribute.
+    PresetMode,
+    /// List of available preset modes.
+    PresetModeList,
+}
+
+/// Vacuum entity features.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum VacuumFeature {
+    OnOff,
+    Start,
+    Stop,
+    Pause,
+    /// The vacuum can be sent back to its dock.
+    Dock,
+    /// The vacuum can be located, e.g. by playing a sound.
+    Locate,
+    /// Cleaning of individual map segments, e.g. rooms.
+    MapSegment,
+    /// Adjustable fan speed.
+    FanSpeed,
+    /// Adjustable suction power.
+    Suction,
+    /// The vacuum reports its status, e.g. cleaned area and time.
+    Status,
+}
+
+/// Vacuum entity commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum VacuumCommand {
+    Start,
+    Stop,
+    Pause,
+    Dock,
+    Locate,
+    SetFanSpeed,
+    /// Start cleaning the given map segments. Requires the `map_segment` feature.
+    StartSegmentCleaning,
+}
+
+/// Vacuum entity device classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum VacuumDeviceClass {
+    /// Robot vacuum cleaner.
+    Vacuum,
+    /// Robot mop.
+    Mop,
+}
+
+/// Vacuum entity attributes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum VacuumAttribute {
+    /// Vacuum state, see [`VacuumState`].
+    State,
+    /// Battery level in percent: 0-100.
+    BatteryLevel,
+    FanSpeed,
+    /// Cleaned area of the current or last cleaning run in square meters.
+    CleanArea,
+    /// Duration of the current or last cleaning run in seconds.
+    CleanTime,
+    /// Device specific error code if the vacuum is in the `ERROR` state.
+    ErrorCode,
+    /// Available map segments for segment cleaning.
+    Segments,
+}
+
+/// Vacuum states, used in the `state` attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum VacuumState {
+    Idle,
+    Cleaning,
+    /// Returning to the dock.
+    Returning,
+    Docked,
+    Paused,
+    Error,
+}
+
+/// Alarm control panel entity features.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum AlarmFeature {
+    Arm,
+    Disarm,
+    ArmAway,
+    ArmHome,
+    ArmNight,
+    /// The alarm can be triggered manually.
+    TriggerAlarm,
+}
+
+/// Alarm control panel entity commands.
+///
+/// All commands accept an optional code, see [`AlarmCodeParams`](crate::AlarmCodeParams).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum AlarmCommand {
+    Disarm,
+    ArmAway,
+    ArmHome,
+    ArmNight,
+    Trigger,
+}
+
+/// Alarm control panel entity attributes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum AlarmAttribute {
+    /// Alarm state, see [`AlarmState`].
+    State,
+    /// Identifier of the user or keypad which last changed the state.
+    ChangedBy,
+    /// Format of the code, see [`AlarmCodeFormat`].
+    CodeFormat,
+    /// A code is required for arming and disarming.
+    CodeRequired,
+}
+
+/// Alarm control panel states, used in the `state` attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlarmState {
+    Disarmed,
+    ArmedAway,
+    ArmedHome,
+    ArmedNight,
+    /// Entry or exit delay before the alarm is triggered.
+    Pending,
+    Triggered,
+    Arming,
+}
+
+/// Alarm control panel code formats, used in the `code_format` attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlarmCodeFormat {
+    /// Numeric PIN code.
+    Number,
+    /// Alphanumeric code.
+    Text,
+}
+
+/// Select entity features.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum SelectFeature {
+    SelectOption,
+}
+
+/// Select entity commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum SelectCommand {
+    /// Select an option. See [`SelectOptionParams`](crate::SelectOptionParams) for the command
+    /// parameters.
+    SelectOption,
+}
+
+/// Select entity option fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum SelectOptionField {
+    /// List of selectable options. Must not be empty.
+    Options,
+}
+
+/// Select entity attributes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[strum(serialize_all = "snake_case")]
+pub enum SelectAttribute {
+    /// Currently selected option.
+    State,
+    /// List of selectable options, if it changes dynamically.
+    Options,
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{MediaPlayerCommand, MediaPlayerFeature};
+    use crate::{
+        ClimateAttribute, ClimateCommand, ClimateFeature, EntityType, LightAttribute, LockFeature,
+        MediaPlayerAttribute, MediaPlayerCommand, MediaPlayerDeviceClass, MediaPlayerFeature,
+        MediaType, SensorDeviceClass, VacuumState,
+    };
     use serde::{Deserialize, Serialize};
     use std::str::FromStr;
 
@@ -665,4 +1376,197 @@ mod tests {
 
         assert_eq!(MediaPlayerCommand::Digit_0, test.cmd);
     }
+
+    #[test]
+    fn serialize_mediaplayer_device_class() {
+        assert_eq!("cd_player", MediaPlayerDeviceClass::CdPlayer.as_ref());
+        assert_eq!(
+            serde_json::json!("cassette_deck"),
+            serde_json::to_value(MediaPlayerDeviceClass::CassetteDeck).unwrap()
+        );
+        assert_eq!(
+            MediaPlayerDeviceClass::MiniDisc,
+            MediaPlayerDeviceClass::from_str("mini_disc").unwrap()
+        );
+    }
+
+    #[test]
+    fn deserialize_entity_type_alias() {
+        let entity_type: EntityType =
+            serde_json::from_value(serde_json::json!("mediaplayer")).expect("Invalid json");
+        assert_eq!(EntityType::MediaPlayer, entity_type);
+        let entity_type: EntityType =
+            serde_json::from_value(serde_json::json!("iremitter")).expect("Invalid json");
+        assert_eq!(EntityType::IrEmitter, entity_type);
+        // aliases are only used for deserialization
+        assert_eq!(
+            serde_json::json!("media_player"),
+            serde_json::to_value(EntityType::MediaPlayer).unwrap()
+        );
+    }
+
+    #[test]
+    fn lock_feature_round_trip() {
+        for (feature, value) in [
+            (LockFeature::Lock, "lock"),
+            (LockFeature::Unlock, "unlock"),
+            (LockFeature::Open, "open"),
+            (LockFeature::Latch, "latch"),
+        ] {
+            assert_eq!(value, feature.as_ref());
+            assert_eq!(feature, LockFeature::from_str(value).unwrap());
+            let json = serde_json::to_value(feature).unwrap();
+            assert_eq!(serde_json::json!(value), json);
+            assert_eq!(
+                feature,
+                serde_json::from_value::<LockFeature>(json).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_vacuum_state() {
+        assert_eq!(
+            serde_json::json!("DOCKED"),
+            serde_json::to_value(VacuumState::Docked).unwrap()
+        );
+        assert_eq!(
+            VacuumState::Returning,
+            VacuumState::from_str("RETURNING").unwrap()
+        );
+    }
+
+    #[test]
+    fn climate_entity_with_fan_mode_round_trip() {
+        let json = serde_json::json!({
+            "entity_id": "climate1",
+            "entity_type": "climate",
+            "name": { "en": "Air conditioner" },
+            "features": ["on_off", "cool", "fan_mode"],
+            "options": {
+                "temperature_unit": "CELSIUS",
+                "hvac_modes": ["OFF", "COOL", "FAN"]
+            },
+            "attributes": {
+                "state": "COOL",
+                "fan_mode": "auto",
+                "fan_mode_list": ["auto", "low", "medium", "high"]
+            }
+        });
+        let entity: crate::intg::AvailableIntgEntity =
+            serde_json::from_value(json.clone()).expect("Invalid json");
+
+        assert_eq!(EntityType::Climate, entity.entity_type);
+        let features: Vec<ClimateFeature> = entity
+            .features
+            .iter()
+            .flatten()
+            .filter_map(|f| ClimateFeature::from_str(f).ok())
+            .collect();
+        assert_eq!(
+            vec![
+                ClimateFeature::OnOff,
+                ClimateFeature::Cool,
+                ClimateFeature::FanMode
+            ],
+            features
+        );
+        let attributes = entity.attributes.as_ref().expect("Missing attributes");
+        assert_eq!(
+            Some(&serde_json::json!("auto")),
+            attributes.get(ClimateAttribute::FanMode.as_ref())
+        );
+        assert_eq!(
+            Some(&serde_json::json!(["auto", "low", "medium", "high"])),
+            attributes.get(ClimateAttribute::FanModeList.as_ref())
+        );
+        assert_eq!(json, serde_json::to_value(&entity).unwrap());
+
+        assert_eq!(
+            ClimateCommand::FanMode,
+            ClimateCommand::from_str("fan_mode").unwrap()
+        );
+    }
+
+    #[test]
+    fn serialize_sensor_device_class() {
+        for (class, value) in [
+            (SensorDeviceClass::Pm25, "pm25"),
+            (SensorDeviceClass::Pm10, "pm10"),
+            (SensorDeviceClass::CarbonDioxide, "carbon_dioxide"),
+            (SensorDeviceClass::NitrogenDioxide, "nitrogen_dioxide"),
+            (SensorDeviceClass::SoundLevel, "sound_level"),
+        ] {
+            assert_eq!(value, class.as_ref());
+            assert_eq!(class, SensorDeviceClass::from_str(value).unwrap());
+            assert_eq!(
+                serde_json::json!(value),
+                serde_json::to_value(class).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_light_effect_attributes() {
+        let mut attributes = serde_json::Map::new();
+        attributes.insert(LightAttribute::Effect.to_string(), "rainbow".into());
+        attributes.insert(
+            LightAttribute::EffectList.to_string(),
+            serde_json::to_value(vec!["rainbow", "candle"]).unwrap(),
+        );
+
+        assert_eq!(
+            serde_json::json!({ "effect": "rainbow", "effect_list": ["rainbow", "candle"] }),
+            serde_json::Value::Object(attributes)
+        );
+    }
+
+    #[test]
+    fn media_player_playlist_serialization() {
+        assert_eq!(
+            serde_json::json!("playlist"),
+            serde_json::to_value(MediaPlayerFeature::Playlist).unwrap()
+        );
+        for (cmd, value) in [
+            (MediaPlayerCommand::PlaylistPrevious, "playlist_previous"),
+            (MediaPlayerCommand::PlaylistNext, "playlist_next"),
+            (MediaPlayerCommand::AddToPlaylist, "add_to_playlist"),
+            (MediaPlayerCommand::ClearPlaylist, "clear_playlist"),
+        ] {
+            assert_eq!(value, cmd.as_ref());
+            assert_eq!(cmd, MediaPlayerCommand::from_str(value).unwrap());
+        }
+        for (attr, value) in [
+            (MediaPlayerAttribute::PlaylistTitle, "playlist_title"),
+            (
+                MediaPlayerAttribute::PlaylistItemCount,
+                "playlist_item_count",
+            ),
+            (MediaPlayerAttribute::PlaylistPosition, "playlist_position"),
+        ] {
+            assert_eq!(value, attr.as_ref());
+            assert_eq!(
+                serde_json::json!(value),
+                serde_json::to_value(attr).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn media_type_serialization() {
+        for (media_type, value) in [
+            (MediaType::Podcast, "PODCAST"),
+            (MediaType::Audiobook, "AUDIOBOOK"),
+            (MediaType::LiveStream, "LIVE_STREAM"),
+            (MediaType::Game, "GAME"),
+        ] {
+            let json = serde_json::to_value(media_type).unwrap();
+            assert_eq!(serde_json::json!(value), json);
+            assert_eq!(
+                media_type,
+                serde_json::from_value::<MediaType>(json).unwrap()
+            );
+            assert_eq!(media_type, MediaType::from_str(value).unwrap());
+        }
+    }
 }
@@ -0,0 +1,567 @@
+// Copyright (c) 2024 Unfolded Circle ApS and contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Typed parameter definitions of entity commands.
+//!
+//! Entity commands transport their parameters as a generic JSON object in the `params` property.
+//! These structs define the parameter objects of commands requiring additional data and can be
+//! converted into the wire format with [`EntityCommandParams::to_params_map`].
+//!
+//! See [entity documentation](https://github.com/unfoldedcircle/core-api/tree/main/doc/entities)
+//! for more information.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use serde_with::skip_serializing_none;
+use validator::ValidationError;
+
+use crate::ClimateOptionField;
+
+/// Parameters of the [`MediaPlayerCommand::SelectSource`](crate::MediaPlayerCommand::SelectSource)
+/// command.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SelectSourceParams {
+    /// Input source to select, one of the values in the `source_list` attribute.
+    pub source: String,
+}
+
+impl SelectSourceParams {
+    /// Create the parameters to select the given input source.
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+        }
+    }
+}
+
+impl EntityCommandParams for SelectSourceParams {}
+
+/// Parameters of the
+/// [`MediaPlayerCommand::SelectSoundMode`](crate::MediaPlayerCommand::SelectSoundMode) command.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SelectSoundModeParams {
+    /// Sound mode to select, one of the values in the `sound_mode_list` attribute.
+    pub sound_mode: String,
+}
+
+impl SelectSoundModeParams {
+    /// Create the parameters to select the given sound mode.
+    pub fn new(sound_mode: impl Into<String>) -> Self {
+        Self {
+            sound_mode: sound_mode.into(),
+        }
+    }
+}
+
+impl EntityCommandParams for SelectSoundModeParams {}
+
+/// Parameters of the [`ClimateCommand::FanMode`](crate::ClimateCommand::FanMode) command.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SetFanModeParams {
+    /// Fan mode to set, one of the values in the `fan_mode_list` attribute. E.g. `auto`, `low`,
+    /// `medium`, `high`.
+    pub fan_mode: String,
+}
+
+impl SetFanModeParams {
+    /// Create the parameters to set the given fan mode.
+    pub fn new(fan_mode: impl Into<String>) -> Self {
+        Self {
+            fan_mode: fan_mode.into(),
+        }
+    }
+}
+
+impl EntityCommandParams for SetFanModeParams {}
+
+/// Parameters of the [`ClimateCommand::SetSwingMode`](crate::ClimateCommand::SetSwingMode)
+/// command.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SetSwingModeParams {
+    /// Swing mode to set, one of the values in the `swing_modes` option.
+    pub swing_mode: String,
+}
+
+impl SetSwingModeParams {
+    /// Create the parameters to set the given swing mode.
+    pub fn new(swing_mode: impl Into<String>) -> Self {
+        Self {
+            swing_mode: swing_mode.into(),
+        }
+    }
+}
+
+impl EntityCommandParams for SetSwingModeParams {}
+
+/// Parameters of the [`LightCommand::On`](crate::LightCommand::On) command.
+///
+/// All parameters are optional and can be combined to switch on the light with the given settings.
+///
+/// # Examples
+///
+/// ```
+/// use uc_api::{EntityCommandParams, LightOnParams};
+/// use validator::Validate;
+/// let params = LightOnParams {
+///     brightness: Some(80),
+///     hue: Some(120),
+///     ..Default::default()
+/// };
+/// assert!(params.validate().is_ok());
+/// let params = params.to_params_map();
+/// assert_eq!(Some(80), params.get("brightness").and_then(|v| v.as_u64()));
+/// assert!(!params.contains_key("saturation"));
+/// ```
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize, Validate)]
+pub struct LightOnParams {
+    /// Brightness in percent: 0-100.
+    #[validate(range(max = 100, message = "Invalid range (max = 100)"))]
+    pub brightness: Option<u8>,
+    /// Color temperature.
+    pub color_temperature: Option<u32>,
+    /// Color hue in degrees: 0-360.
+    #[validate(range(max = 360, message = "Invalid range (max = 360)"))]
+    pub hue: Option<u16>,
+    /// Color saturation in percent: 0-100.
+    #[validate(range(max = 100, message = "Invalid range (max = 100)"))]
+    pub saturation: Option<u8>,
+    /// Light effect to activate.
+    pub effect: Option<String>,
+}
+
+impl EntityCommandParams for LightOnParams {}
+
+/// Parameters of the [`LightCommand::Effect`](crate::LightCommand::Effect) command.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LightEffectParams {
+    /// Lighting effect to activate, one of the values in the `effect_list` attribute.
+    pub effect: String,
+}
+
+impl LightEffectParams {
+    /// Create the parameters to activate the given light effect.
+    pub fn new(effect: impl Into<String>) -> Self {
+        Self {
+            effect: effect.into(),
+        }
+    }
+}
+
+impl EntityCommandParams for LightEffectParams {}
+
+/// Parameters of the [`CoverCommand::Position`](crate::CoverCommand::Position) command.
+///
+/// # Examples
+///
+/// ```
+/// use uc_api::SetPositionParams;
+/// use validator::Validate;
+/// assert!(SetPositionParams::new(100).validate().is_ok());
+/// assert!(SetPositionParams::new(101).validate().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Validate)]
+pub struct SetPositionParams {
+    /// Cover position in percent: 0 = closed, 100 = open.
+    #[validate(range(max = 100, message = "Invalid range (max = 100)"))]
+    pub position: u8,
+}
+
+impl SetPositionParams {
+    /// Create the parameters to move the cover to the given position.
+    pub fn new(position: u8) -> Self {
+        Self { position }
+    }
+}
+
+impl EntityCommandParams for SetPositionParams {}
+
+/// Parameters of the [`FanCommand::Speed`](crate::FanCommand::Speed) command.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Validate)]
+pub struct SetFanSpeedParams {
+    /// Fan speed in percent: 0-100.
+    #[validate(range(max = 100, message = "Invalid range (max = 100)"))]
+    pub speed: u8,
+}
+
+impl SetFanSpeedParams {
+    /// Create the parameters to set the given fan speed.
+    pub fn new(speed: u8) -> Self {
+        Self { speed }
+    }
+}
+
+impl EntityCommandParams for SetFanSpeedParams {}
+
+/// Parameters of the [`AlarmCommand`](crate::AlarmCommand) commands.
+///
+/// The code is always transmitted as a string to preserve leading zeros of numeric PIN codes.
+/// Numeric JSON values are accepted when deserializing.
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use uc_api::{AlarmCodeParams, EntityCommandParams};
+/// let params: AlarmCodeParams = serde_json::from_value(json!({ "code": 1234 })).unwrap();
+/// assert_eq!(Some("1234"), params.code.as_deref());
+/// let params = AlarmCodeParams::new("0815").to_params_map();
+/// assert_eq!(Some(&json!("0815")), params.get("code"));
+/// ```
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AlarmCodeParams {
+    /// Optional code to arm or disarm the alarm, see the `code_format` attribute.
+    #[serde(default, deserialize_with = "deserialize_code")]
+    pub code: Option<String>,
+}
+
+impl AlarmCodeParams {
+    /// Create the parameters with the given alarm code.
+    pub fn new(code: impl Into<String>) -> Self {
+        Self {
+            code: Some(code.into()),
+        }
+    }
+}
+
+impl EntityCommandParams for AlarmCodeParams {}
+
+/// Deserialize an optional code from a JSON string or number.
+fn deserialize_code<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(code)) => Ok(Some(code)),
+        Some(Value::Number(code)) => Ok(Some(code.to_string())),
+        Some(_) => Err(serde::de::Error::custom(
+            "code must be a string or a number",
+        )),
+    }
+}
+
+/// Parameters of the [`CoverCommand::TiltPosition`](crate::CoverCommand::TiltPosition) command.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Validate)]
+pub struct SetTiltPositionParams {
+    /// Tilt position in percent: 0-100.
+    #[validate(range(max = 100, message = "Invalid range (max = 100)"))]
+    pub tilt_position: u8,
+}
+
+impl SetTiltPositionParams {
+    /// Create the parameters to tilt the cover to the given position.
+    pub fn new(tilt_position: u8) -> Self {
+        Self { tilt_position }
+    }
+}
+
+impl EntityCommandParams for SetTiltPositionParams {}
+
+/// Parameters of the [`SelectCommand::SelectOption`](crate::SelectCommand::SelectOption) command.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SelectOptionParams {
+    /// Option to select, one of the values in the `options` option.
+    pub option: String,
+}
+
+impl SelectOptionParams {
+    /// Create the parameters to select the given option.
+    pub fn new(option: impl Into<String>) -> Self {
+        Self {
+            option: option.into(),
+        }
+    }
+}
+
+impl EntityCommandParams for SelectOptionParams {}
+
+/// Parameters of the [`ClimateCommand::TargetTemperature`](crate::ClimateCommand::TargetTemperature)
+/// command.
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use uc_api::SetTemperatureParams;
+/// let options = json!({ "min_temperature": 10, "max_temperature": 30, "target_temperature_step": 0.5 });
+/// let options = options.as_object().unwrap();
+/// assert!(SetTemperatureParams::new(21.5).validate_options(options).is_ok());
+/// assert!(SetTemperatureParams::new(21.2).validate_options(options).is_err());
+/// assert!(SetTemperatureParams::new(35.0).validate_options(options).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SetTemperatureParams {
+    /// Target temperature in the unit of the `temperature_unit` option.
+    pub temperature: f32,
+}
+
+impl SetTemperatureParams {
+    /// Create the parameters to set the given target temperature.
+    pub fn new(temperature: f32) -> Self {
+        Self { temperature }
+    }
+
+    /// Validate the target temperature against the options of the climate entity.
+    ///
+    /// The temperature must be within the `min_temperature` and `max_temperature` range, and
+    /// aligned to the `target_temperature_step` starting from `min_temperature`. Options which
+    /// are not set are not checked.
+    pub fn validate_options(&self, options: &Map<String, Value>) -> Result<(), ValidationError> {
+        validate_temperature(self.temperature, options)
+    }
+}
+
+impl EntityCommandParams for SetTemperatureParams {}
+
+/// Parameters of the
+/// [`ClimateCommand::TargetTemperatureRange`](crate::ClimateCommand::TargetTemperatureRange)
+/// command.
+///
+/// # Examples
+///
+/// ```
+/// use uc_api::SetTemperatureRangeParams;
+/// use validator::Validate;
+/// assert!(SetTemperatureRangeParams::new(20.0, 24.0).validate().is_ok());
+/// assert!(SetTemperatureRangeParams::new(24.0, 20.0).validate().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Validate)]
+#[validate(schema(function = "validate_temperature_range"))]
+pub struct SetTemperatureRangeParams {
+    /// Lower bound of the target temperature range.
+    pub target_temperature_low: f32,
+    /// Upper bound of the target temperature range.
+    pub target_temperature_high: f32,
+}
+
+impl SetTemperatureRangeParams {
+    /// Create the parameters to set the given target temperature range.
+    pub fn new(target_temperature_low: f32, target_temperature_high: f32) -> Self {
+        Self {
+            target_temperature_low,
+            target_temperature_high,
+        }
+    }
+
+    /// Validate the target temperature range against the options of the climate entity.
+    ///
+    /// Both temperatures are validated as in [`SetTemperatureParams::validate_options`], and the
+    /// upper bound must be greater than the lower bound.
+    pub fn validate_options(&self, options: &Map<String, Value>) -> Result<(), ValidationError> {
+        validate_temperature_range(self)?;
+        validate_temperature(self.target_temperature_low, options)?;
+        validate_temperature(self.target_temperature_high, options)
+    }
+}
+
+impl EntityCommandParams for SetTemperatureRangeParams {}
+
+/// The upper bound of the target temperature range must be greater than the lower bound.
+fn validate_temperature_range(params: &SetTemperatureRangeParams) -> Result<(), ValidationError> {
+    if params.target_temperature_high <= params.target_temperature_low {
+        let mut error = ValidationError::new("range");
+        error.message =
+            Some("target_temperature_high must be greater than target_temperature_low".into());
+        return Err(error);
+    }
+    Ok(())
+}
+
+fn validate_temperature(
+    temperature: f32,
+    options: &Map<String, Value>,
+) -> Result<(), ValidationError> {
+    let option = |field: ClimateOptionField| options.get(field.as_ref()).and_then(|v| v.as_f64());
+    let temperature = f64::from(temperature);
+    let min = option(ClimateOptionField::MinTemperature);
+
+    if min.is_some_and(|min| temperature < min)
+        || option(ClimateOptionField::MaxTemperature).is_some_and(|max| temperature > max)
+    {
+        return Err(ValidationError::new("range"));
+    }
+    if let Some(step) = option(ClimateOptionField::TargetTemperatureStep).filter(|s| *s > 0.0) {
+        let steps = (temperature - min.unwrap_or_default()) / step;
+        // allow for floating point inaccuracies of the f32 temperature value
+        if (steps - steps.round()).abs() > 1e-3 {
+            return Err(ValidationError::new("step"));
+        }
+    }
+    Ok(())
+}
+
+/// Parameters of the [`MediaPlayerCommand::Volume`](crate::MediaPlayerCommand::Volume) command.
+///
+/// `validate()` checks the default volume range of 0-100. Use
+/// [`validate_max_steps`](Self::validate_max_steps) instead if the media player entity sets the
+/// `volume_steps` option.
+///
+/// # Examples
+///
+/// ```
+/// use uc_api::SetVolumeParams;
+/// use validator::Validate;
+/// assert!(SetVolumeParams::new(50).validate().is_ok());
+/// assert!(SetVolumeParams::new(101).validate().is_err());
+/// assert!(SetVolumeParams::new(150).validate_max_steps(Some(200)).is_ok());
+/// assert!(SetVolumeParams::new(50).validate_max_steps(Some(40)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Validate)]
+pub struct SetVolumeParams {
+    /// Volume level: 0-100, or 0 to the `volume_steps` option if set.
+    #[validate(range(max = 100, message = "Invalid range (max = 100)"))]
+    pub volume: u32,
+}
+
+impl SetVolumeParams {
+    /// Create the parameters to set the given volume level.
+    pub fn new(volume: u32) -> Self {
+        Self { volume }
+    }
+
+    /// Validate the volume against the maximum number of volume steps of the media player entity.
+    ///
+    /// `max_steps` is the value of the `volume_steps` option, see
+    /// [`MediaPlayerOptionField::volume_steps`](crate::MediaPlayerOptionField::volume_steps). The
+    /// default range of 0-100 is used if not set, which is the same check as `validate()`.
+    pub fn validate_max_steps(&self, max_steps: Option<u32>) -> Result<(), ValidationError> {
+        if self.volume > max_steps.unwrap_or(100) {
+            return Err(ValidationError::new("range"));
+        }
+        Ok(())
+    }
+}
+
+impl EntityCommandParams for SetVolumeParams {}
+
+/// Parameters of the [`MediaPlayerCommand::Seek`](crate::MediaPlayerCommand::Seek) command.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SeekParams {
+    /// Media position to seek to in seconds.
+    pub media_position: f64,
+}
+
+impl SeekParams {
+    /// Create the parameters to seek to the given media position.
+    pub fn new(media_position: f64) -> Self {
+        Self { media_position }
+    }
+}
+
+impl EntityCommandParams for SeekParams {}
+
+/// Parameters of an entity command, transported as JSON object in the `params` property.
+///
+/// # Examples
+///
+/// ```
+/// use uc_api::{EntityCommandParams, SelectSourceParams};
+/// let params = SelectSourceParams::new("HDMI 1").to_params_map();
+/// assert_eq!(Some("HDMI 1"), params.get("source").and_then(|v| v.as_str()));
+/// ```
+pub trait EntityCommandParams: Serialize {
+    /// Convert the parameters into the `params` object of an entity command.
+    ///
+    /// All parameter structs are plain data structures serializing into a JSON object, an empty
+    /// map is only returned if that's not the case.
+    fn to_params_map(&self) -> Map<String, Value> {
+        match serde_json::to_value(self) {
+            Ok(Value::Object(map)) => map,
+            _ => Map::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use validator::Validate;
+
+    #[test]
+    fn select_source_params_to_params_map() {
+        let params = SelectSourceParams::new("HDMI 1");
+        assert_eq!(
+            json!({ "source": "HDMI 1" }),
+            Value::Object(params.to_params_map())
+        );
+    }
+
+    #[test]
+    fn light_on_params_validation() {
+        let params = LightOnParams {
+            brightness: Some(101),
+            hue: Some(361),
+            saturation: Some(100),
+            ..Default::default()
+        };
+        let errors = params.validate().expect_err("Invalid params must fail");
+        let fields = errors.field_errors();
+        assert!(fields.contains_key("brightness"));
+        assert!(fields.contains_key("hue"));
+        assert!(!fields.contains_key("saturation"));
+    }
+
+    #[test]
+    fn fan_speed_params_validation() {
+        assert!(SetFanSpeedParams::new(100).validate().is_ok());
+        assert!(SetFanSpeedParams::new(101).validate().is_err());
+    }
+
+    #[test]
+    fn alarm_code_params_round_trip() {
+        for (json, code) in [
+            (json!({ "code": "0815" }), Some("0815")),
+            (json!({ "code": 4711 }), Some("4711")),
+            (json!({ "code": "Secret1" }), Some("Secret1")),
+            (json!({}), None),
+        ] {
+            let params: AlarmCodeParams = serde_json::from_value(json).expect("Invalid json");
+            assert_eq!(code, params.code.as_deref());
+            let params: AlarmCodeParams =
+                serde_json::from_value(Value::Object(params.to_params_map())).unwrap();
+            assert_eq!(code, params.code.as_deref());
+        }
+
+        let result: Result<AlarmCodeParams, _> = serde_json::from_value(json!({ "code": [1] }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn select_sound_mode_params_from_params_map() {
+        let json = json!({ "sound_mode": "Surround" });
+        let params: SelectSoundModeParams =
+            serde_json::from_value(json).expect("Invalid json message");
+        assert_eq!(SelectSoundModeParams::new("Surround"), params);
+    }
+
+    #[test]
+    fn volume_default_range() {
+        for volume in [0, 100, 101] {
+            let params = SetVolumeParams::new(volume);
+            assert_eq!(
+                params.validate().is_ok(),
+                params.validate_max_steps(None).is_ok(),
+                "{volume}"
+            );
+        }
+        assert!(SetVolumeParams::new(100).validate().is_ok());
+        assert!(SetVolumeParams::new(101).validate().is_err());
+    }
+
+    #[test]
+    fn volume_range_with_volume_steps() {
+        let params = SetVolumeParams::new(150);
+        assert!(params.validate_max_steps(None).is_err());
+        assert!(params.validate_max_steps(Some(100)).is_err());
+        assert!(params.validate_max_steps(Some(200)).is_ok());
+        assert!(SetVolumeParams::new(200)
+            .validate_max_steps(Some(200))
+            .is_ok());
+        assert!(SetVolumeParams::new(201)
+            .validate_max_steps(Some(200))
+            .is_err());
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright (c) 2024 Unfolded Circle ApS and contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Validated identifier types.
+//!
+//! The identifier fields of the model structs, e.g. `IntegrationDriver::driver_id` or
+//! `AvailableIntgEntity::entity_id`, are still plain `String`s: changing their types would break
+//! existing struct literals and string assignments. The identifier types can be used to validate
+//! and pass identifiers in application code until the models adopt them.
+
+use crate::REGEX_ID_CHARS;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Maximum length of an identifier.
+const MAX_ID_LENGTH: usize = 36;
+
+/// Check the identifier length and allowed characters: `a-z`, `A-Z`, `0-9`, `-` and `_`.
+fn validate_id(id: &str) -> Result<(), &'static str> {
+    if id.is_empty() || id.len() > MAX_ID_LENGTH {
+        return Err("Invalid length (min = 1, max = 36)");
+    }
+    if !REGEX_ID_CHARS.is_match(id) {
+        return Err("Invalid characters (a-z, A-Z, 0-9, - and _ allowed)");
+    }
+    Ok(())
+}
+
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+        #[serde(try_from = "String", into = "String")]
+        pub struct $name(String);
+
+        impl $name {
+            /// Create a new identifier.
+            ///
+            /// # Errors
+            ///
+            /// Validation error message if the identifier is empty, longer than 36 characters or
+            /// contains invalid characters.
+            pub fn new(id: impl Into<String>) -> Result<Self, &'static str> {
+                let id = id.into();
+                validate_id(&id)?;
+                Ok(Self(id))
+            }
+
+            /// Get the identifier as string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = &'static str;
+
+            fn try_from(id: String) -> Result<Self, Self::Error> {
+                Self::new(id)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+    };
+}
+
+id_type!(
+    /// Integration driver identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uc_api::DriverId;
+    /// let id = DriverId::new("my_driver").unwrap();
+    /// assert_eq!(id, "my_driver");
+    /// assert!(DriverId::new("my driver").is_err());
+    /// ```
+    DriverId
+);
+
+id_type!(
+    /// Entity identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uc_api::EntityId;
+    /// let id: EntityId = serde_json::from_str("\"light-1\"").unwrap();
+    /// assert_eq!("light-1", id.to_string());
+    /// assert!(serde_json::from_str::<EntityId>("\"\"").is_err());
+    /// ```
+    EntityId
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn id_validation() {
+        assert!(DriverId::new("a").is_ok());
+        assert!(DriverId::new("a".repeat(36)).is_ok());
+        assert!(DriverId::new("uc_hass-1").is_ok());
+        assert!(DriverId::new("").is_err());
+        assert!(DriverId::new("a".repeat(37)).is_err());
+        assert!(EntityId::new("light.1").is_err());
+        assert!(EntityId::new("light 1").is_err());
+    }
+
+    #[test]
+    fn id_serde_round_trip() {
+        let id: EntityId = serde_json::from_value(serde_json::json!("light1")).unwrap();
+        assert_eq!(id, "light1");
+        assert_eq!(
+            serde_json::json!("light1"),
+            serde_json::to_value(&id).unwrap()
+        );
+        assert_eq!("light1", String::from(id));
+
+        assert!(serde_json::from_value::<DriverId>(serde_json::json!("foo bar")).is_err());
+    }
+}
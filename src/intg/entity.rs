@@ -5,12 +5,28 @@
 
 use std::collections::HashMap;
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
+use strum::VariantNames;
 use strum_macros::{AsRefStr, Display, EnumString, VariantNames};
+use validator::ValidationError;
 
-use crate::{EntityType, REGEX_ID_CHARS};
+use crate::intg::ws::{DriverEvent, R2Request};
+use crate::ws::{EventCategory, WsMessage};
+use crate::{
+    ActivityCommand, ActivityFeature, AlarmAttribute, AlarmCommand, AlarmFeature, ButtonAttribute,
+    ButtonCommand, ButtonFeature, ClimateAttribute, ClimateCommand, ClimateFeature,
+    ClimateOptionField, CoverAttribute, CoverCommand, CoverFeature, EntityCommandParams,
+    EntityDeviceClass, EntityType, FanAttribute, FanCommand, FanFeature, FanOptionField,
+    IrEmitterAttribute, LightAttribute, LightCommand, LightFeature, LightOptionField,
+    LockAttribute, LockCommand, LockFeature, MacroCommand, MacroFeature, MediaPlayerAttribute,
+    MediaPlayerCommand, MediaPlayerFeature, MediaPlayerOptionField, RemoteAttribute,
+    SelectAttribute, SelectCommand, SelectFeature, SelectOptionField, SensorAttribute,
+    SensorOptionField, SwitchAttribute, SwitchCommand, SwitchFeature, SwitchOptionField,
+    VacuumAttribute, VacuumCommand, VacuumFeature, REGEX_ID_CHARS,
+};
 
 /// Execute an entity command.
 ///
@@ -21,6 +37,10 @@ use crate::{EntityType, REGEX_ID_CHARS};
 /// After successfully executing a command, the remote expects an `entity_change` event with the updated feature
 /// value(s). The immediate `result` response is to acknowledge the command or to return any immediate failures in
 /// case the driver already knows it's unable to perform the command due to device communication issues etc.
+///
+/// Typed parameter structs for commands requiring parameters: see [`crate::SelectSourceParams`]
+/// for the common entities, [`IntgRemoteSendParams`] and [`IntgRemoteSendSequenceParams`] for the
+/// remote entity.
 #[skip_serializing_none]
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct EntityCommand {
@@ -31,6 +51,13 @@ pub struct EntityCommand {
     pub params: Option<serde_json::Map<String, Value>>,
 }
 
+impl EntityCommand {
+    /// Create an `entity_command` request message for the command.
+    pub fn to_ws_message(&self, req_id: u32) -> Result<WsMessage, serde_json::Error> {
+        WsMessage::request(req_id, R2Request::EntityCommand.as_ref(), self)
+    }
+}
+
 /// Entity state change event.
 ///
 /// Emitted when an attribute of an entity changes, e.g. is switched off. Either after an `entity_command` or if the
@@ -47,6 +74,42 @@ pub struct EntityChange {
     pub attributes: serde_json::Map<String, Value>,
 }
 
+impl EntityChange {
+    /// Check if the change event is for the given entity.
+    ///
+    /// If no `device_id` is given, the entity of all devices is matched.
+    pub fn matches_entity(&self, entity_id: &str, device_id: Option<&str>) -> bool {
+        self.entity_id == entity_id
+            && (device_id.is_none() || self.device_id.as_deref() == device_id)
+    }
+
+    /// Create an `entity_change` event message for the change event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use uc_api::EntityType;
+    /// use uc_api::intg::EntityChange;
+    /// let change = EntityChange {
+    ///     device_id: None,
+    ///     entity_type: EntityType::Switch,
+    ///     entity_id: "switch-1".into(),
+    ///     attributes: json!({ "state": "ON" }).as_object().unwrap().clone(),
+    /// };
+    /// let msg = change.to_ws_message().unwrap();
+    /// assert_eq!(Some("event"), msg.kind.as_deref());
+    /// assert_eq!(Some("entity_change"), msg.msg.as_deref());
+    /// ```
+    pub fn to_ws_message(&self) -> Result<WsMessage, serde_json::Error> {
+        Ok(WsMessage::event(
+            DriverEvent::EntityChange.as_msg_str(),
+            EventCategory::Entity,
+            serde_json::to_value(self)?,
+        ))
+    }
+}
+
 /// Available entity definition provided by an integration.
 ///
 /// The `entity_type` value acts as discriminator for the entity type, which defines the supported
@@ -58,6 +121,7 @@ pub struct EntityChange {
 /// See entity documentation for more information about the individual entity features and options.
 #[skip_serializing_none]
 #[derive(Debug, Clone, Deserialize, Serialize, Validate)]
+#[validate(schema(function = "validate_entity_options"))]
 pub struct AvailableIntgEntity {
     /// Unique entity identifier within the integration device.
     #[validate(length(
@@ -88,6 +152,14 @@ pub struct AvailableIntgEntity {
     /// Optional area if supported by the integration. E.g. `Living room`.
     #[validate(length(max = 50, message = "Invalid length (max = 50)"))]
     pub area: Option<String>,
+    /// Optional hierarchical location of the entity if supported by the integration, from the
+    /// outermost to the innermost grouping. E.g. `["Ground floor", "Living room"]`.
+    ///
+    /// The innermost entry should correspond to `area` if both are provided. At most 10 entries
+    /// are allowed, each entry has the same length limit as `area`.
+    #[validate(length(max = 10, message = "Invalid length (max = 10)"))]
+    #[validate(custom = "validate_area_path")]
+    pub area_path: Option<Vec<String>>,
     /// Optional entity options. See entity documentation for available options.
     pub options: Option<serde_json::Map<String, Value>>,
     /// Optional entity attributes.
@@ -98,6 +170,113 @@ pub struct AvailableIntgEntity {
     pub attributes: Option<serde_json::Map<String, Value>>,
 }
 
+impl AvailableIntgEntity {
+    /// Get the typed device class of the entity.
+    ///
+    /// Returns `None` if no device class is set, the entity type doesn't support device classes or
+    /// the device class is unknown for the entity type.
+    pub fn typed_device_class(&self) -> Option<EntityDeviceClass> {
+        self.device_class
+            .as_deref()
+            .and_then(|class| EntityDeviceClass::from_entity_type(self.entity_type, class))
+    }
+
+    /// Set an entity option.
+    ///
+    /// The options map is created if not yet present, an existing option value is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use uc_api::EntityType;
+    /// use uc_api::intg::AvailableIntgEntity;
+    /// let mut entity = AvailableIntgEntity {
+    ///     entity_id: "player".into(),
+    ///     device_id: None,
+    ///     entity_type: EntityType::MediaPlayer,
+    ///     device_class: None,
+    ///     name: HashMap::from([("en".into(), "Player".into())]),
+    ///     features: None,
+    ///     area: None,
+    ///     area_path: None,
+    ///     options: None,
+    ///     attributes: None,
+    /// };
+    /// entity.set_option("simple_commands", vec!["INPUT_1", "INPUT_2"]).unwrap();
+    /// entity.set_option("volume_steps", 50).unwrap();
+    ///
+    /// assert_eq!(Some(50), entity.get_option_as::<u32>("volume_steps"));
+    /// assert_eq!(
+    ///     Some(vec!["INPUT_1".to_string(), "INPUT_2".to_string()]),
+    ///     entity.get_option_as::<Vec<String>>("simple_commands")
+    /// );
+    /// assert!(entity.remove_option("volume_steps").is_some());
+    /// assert_eq!(None, entity.get_option_as::<u32>("volume_steps"));
+    /// ```
+    pub fn set_option<T: Serialize>(
+        &mut self,
+        key: impl AsRef<str>,
+        value: T,
+    ) -> Result<(), serde_json::Error> {
+        let value = serde_json::to_value(value)?;
+        self.options
+            .get_or_insert_with(Default::default)
+            .insert(key.as_ref().to_string(), value);
+        Ok(())
+    }
+
+    /// Get an entity option converted to the given type.
+    ///
+    /// Returns `None` if the option is not set or cannot be converted.
+    pub fn get_option_as<T: DeserializeOwned>(&self, key: impl AsRef<str>) -> Option<T> {
+        self.options
+            .as_ref()
+            .and_then(|options| options.get(key.as_ref()))
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    /// Remove an entity option and return its value.
+    pub fn remove_option(&mut self, key: impl AsRef<str>) -> Option<Value> {
+        self.options
+            .as_mut()
+            .and_then(|options| options.remove(key.as_ref()))
+    }
+}
+
+/// Entity type specific validation of the entity options.
+///
+/// - `select` entity: the `options` option must be a non-empty list of strings.
+fn validate_entity_options(entity: &AvailableIntgEntity) -> Result<(), ValidationError> {
+    if entity.entity_type == EntityType::Select {
+        let valid = entity
+            .options
+            .as_ref()
+            .and_then(|options| options.get(SelectOptionField::Options.as_ref()))
+            .and_then(|v| v.as_array())
+            .is_some_and(|list| !list.is_empty() && list.iter().all(|v| v.is_string()));
+        if !valid {
+            let mut error = ValidationError::new("INVALID_OPTIONS");
+            error.message = Some("Select entity requires a non-empty list of options".into());
+            return Err(error);
+        }
+    }
+    Ok(())
+}
+
+/// Each area path entry must be a non-empty string with at most 50 characters, like `area`.
+fn validate_area_path(area_path: &[String]) -> Result<(), ValidationError> {
+    if area_path
+        .iter()
+        .any(|entry| entry.is_empty() || entry.chars().count() > 50)
+    {
+        let mut error = ValidationError::new("length");
+        error.message = Some("Invalid area path entry length (min = 1, max = 50)".into());
+        return Err(error);
+    }
+    Ok(())
+}
+
 /// Integration-API remote entity option fields.
 ///
 /// Attention: only valid in the Integration-API data model. See [crate::core::RemoteOptionField]
@@ -143,8 +322,128 @@ pub enum IntgRemoteCommand {
     Toggle,
     SendCmd,
     SendCmdSequence,
+    /// Learn an IR code. See [`LearnIrParams`] for the command parameters.
+    LearnIr,
+}
+
+/// Parameters of the Integration-API remote [`IntgRemoteCommand::SendCmd`] command.
+///
+/// # Examples
+///
+/// ```
+/// use uc_api::EntityCommandParams;
+/// use uc_api::intg::IntgRemoteSendParams;
+/// let params = IntgRemoteSendParams::new("VOLUME_UP").to_params_map();
+/// assert_eq!(Some("VOLUME_UP"), params.get("command").and_then(|v| v.as_str()));
+/// assert!(!params.contains_key("repeat"));
+/// ```
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct IntgRemoteSendParams {
+    /// Command identifier to send, e.g. one of the `simple_commands` of the remote.
+    pub command: String,
+    /// Optional number of repetitions.
+    pub repeat: Option<u8>,
+}
+
+impl IntgRemoteSendParams {
+    /// Create the parameters to send the given command once.
+    pub fn new(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+            repeat: None,
+        }
+    }
 }
 
+impl EntityCommandParams for IntgRemoteSendParams {}
+
+/// Parameters of the Integration-API remote [`IntgRemoteCommand::SendCmdSequence`] command.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct IntgRemoteSendSequenceParams {
+    /// Commands to send in the given order.
+    pub sequence: Vec<IntgSequenceEntry>,
+    /// Optional default delay in milliseconds between the commands.
+    pub delay: Option<u32>,
+}
+
+impl IntgRemoteSendSequenceParams {
+    /// Create the parameters to send the given command sequence without a default delay.
+    pub fn new(sequence: Vec<IntgSequenceEntry>) -> Self {
+        Self {
+            sequence,
+            delay: None,
+        }
+    }
+}
+
+impl EntityCommandParams for IntgRemoteSendSequenceParams {}
+
+/// Single command of an [`IntgRemoteSendSequenceParams`] command sequence.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct IntgSequenceEntry {
+    /// Command identifier to send.
+    pub command: String,
+    /// Optional delay in milliseconds after sending the command. Overrides the sequence delay.
+    pub delay: Option<u32>,
+}
+
+impl IntgSequenceEntry {
+    /// Create a sequence entry for the given command, using the delay of the sequence.
+    pub fn new(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+            delay: None,
+        }
+    }
+}
+
+/// Parameters of the Integration-API remote [`IntgRemoteCommand::LearnIr`] command.
+///
+/// Starts learning an IR code, which is stored as `command` in the given IR dataset.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LearnIrParams {
+    /// Optional associated device, only if the integration driver supports multiple devices.
+    pub device_id: Option<String>,
+    /// Entity receiving the IR code.
+    pub entity_id: String,
+    /// Identifier of the IR dataset to store the learned code in.
+    pub ir_dataset: String,
+    /// Command identifier of the learned code.
+    pub command: String,
+    /// Optional timeout in seconds to wait for an IR code.
+    pub timeout_secs: Option<u32>,
+}
+
+impl LearnIrParams {
+    /// Create the parameters to learn an IR code.
+    ///
+    /// - `entity_id`: entity receiving the IR code.
+    /// - `ir_dataset`: identifier of the IR dataset to store the learned code in.
+    /// - `command`: command identifier of the learned code.
+    ///
+    /// The optional `device_id` and `timeout_secs` (timeout in seconds to wait for an IR code)
+    /// fields are not set.
+    pub fn new(
+        entity_id: impl Into<String>,
+        ir_dataset: impl Into<String>,
+        command: impl Into<String>,
+    ) -> Self {
+        Self {
+            device_id: None,
+            entity_id: entity_id.into(),
+            ir_dataset: ir_dataset.into(),
+            command: command.into(),
+            timeout_secs: None,
+        }
+    }
+}
+
+impl EntityCommandParams for LearnIrParams {}
+
 /// Integration-API IR-emitter features.
 ///
 /// Attention: only valid in the Integration-API data model. See [crate::core::IrEmitterFeature]
@@ -182,3 +481,286 @@ pub enum IntgIrEmitterOptionField {
     Ports,
     IrFormats,
 }
+
+/// Capabilities of an entity type in the Integration-API data model.
+///
+/// Aggregates the supported features, commands, attributes and option fields of an entity type
+/// for introspection, e.g. for capability driven code generation or documentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntgEntityCaps {
+    pub entity_type: EntityType,
+    pub features: &'static [&'static str],
+    pub commands: &'static [&'static str],
+    pub attributes: &'static [&'static str],
+    pub options: &'static [&'static str],
+}
+
+impl IntgEntityCaps {
+    /// Get the capabilities of the given entity type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uc_api::EntityType;
+    /// use uc_api::intg::IntgEntityCaps;
+    /// let caps = IntgEntityCaps::for_type(EntityType::Light);
+    /// assert!(caps.features.contains(&"dim"));
+    /// assert!(caps.commands.contains(&"toggle"));
+    /// assert!(caps.attributes.contains(&"brightness"));
+    /// ```
+    pub fn for_type(entity_type: EntityType) -> &'static IntgEntityCaps {
+        match entity_type {
+            EntityType::Button => &BUTTON_CAPS,
+            EntityType::Switch => &SWITCH_CAPS,
+            EntityType::Climate => &CLIMATE_CAPS,
+            EntityType::Cover => &COVER_CAPS,
+            EntityType::Light => &LIGHT_CAPS,
+            EntityType::MediaPlayer => &MEDIA_PLAYER_CAPS,
+            EntityType::Sensor => &SENSOR_CAPS,
+            EntityType::Activity => &ACTIVITY_CAPS,
+            EntityType::Macro => &MACRO_CAPS,
+            EntityType::Remote => &REMOTE_CAPS,
+            EntityType::IrEmitter => &IR_EMITTER_CAPS,
+            EntityType::Lock => &LOCK_CAPS,
+            EntityType::Fan => &FAN_CAPS,
+            EntityType::Vacuum => &VACUUM_CAPS,
+            EntityType::AlarmControlPanel => &ALARM_CONTROL_PANEL_CAPS,
+            EntityType::Select => &SELECT_CAPS,
+        }
+    }
+}
+
+static BUTTON_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::Button,
+    features: ButtonFeature::VARIANTS,
+    commands: ButtonCommand::VARIANTS,
+    attributes: ButtonAttribute::VARIANTS,
+    options: &[],
+};
+
+static SWITCH_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::Switch,
+    features: SwitchFeature::VARIANTS,
+    commands: SwitchCommand::VARIANTS,
+    attributes: SwitchAttribute::VARIANTS,
+    options: SwitchOptionField::VARIANTS,
+};
+
+static CLIMATE_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::Climate,
+    features: ClimateFeature::VARIANTS,
+    commands: ClimateCommand::VARIANTS,
+    attributes: ClimateAttribute::VARIANTS,
+    options: ClimateOptionField::VARIANTS,
+};
+
+static COVER_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::Cover,
+    features: CoverFeature::VARIANTS,
+    commands: CoverCommand::VARIANTS,
+    attributes: CoverAttribute::VARIANTS,
+    options: &[],
+};
+
+static LIGHT_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::Light,
+    features: LightFeature::VARIANTS,
+    commands: LightCommand::VARIANTS,
+    attributes: LightAttribute::VARIANTS,
+    options: LightOptionField::VARIANTS,
+};
+
+static MEDIA_PLAYER_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::MediaPlayer,
+    features: MediaPlayerFeature::VARIANTS,
+    commands: MediaPlayerCommand::VARIANTS,
+    attributes: MediaPlayerAttribute::VARIANTS,
+    options: MediaPlayerOptionField::VARIANTS,
+};
+
+static SENSOR_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::Sensor,
+    features: &[],
+    commands: &[],
+    attributes: SensorAttribute::VARIANTS,
+    options: SensorOptionField::VARIANTS,
+};
+
+static ACTIVITY_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::Activity,
+    features: ActivityFeature::VARIANTS,
+    commands: ActivityCommand::VARIANTS,
+    attributes: &[],
+    options: &[],
+};
+
+static MACRO_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::Macro,
+    features: MacroFeature::VARIANTS,
+    commands: MacroCommand::VARIANTS,
+    attributes: &[],
+    options: &[],
+};
+
+static REMOTE_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::Remote,
+    features: IntgRemoteFeature::VARIANTS,
+    commands: IntgRemoteCommand::VARIANTS,
+    attributes: RemoteAttribute::VARIANTS,
+    options: IntgRemoteOptionField::VARIANTS,
+};
+
+static IR_EMITTER_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::IrEmitter,
+    features: IntgIrEmitterFeature::VARIANTS,
+    commands: IntgIrEmitterCommand::VARIANTS,
+    attributes: IrEmitterAttribute::VARIANTS,
+    options: IntgIrEmitterOptionField::VARIANTS,
+};
+
+static LOCK_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::Lock,
+    features: LockFeature::VARIANTS,
+    commands: LockCommand::VARIANTS,
+    attributes: LockAttribute::VARIANTS,
+    options: &[],
+};
+
+static FAN_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::Fan,
+    features: FanFeature::VARIANTS,
+    commands: FanCommand::VARIANTS,
+    attributes: FanAttribute::VARIANTS,
+    options: FanOptionField::VARIANTS,
+};
+
+static VACUUM_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::Vacuum,
+    features: VacuumFeature::VARIANTS,
+    commands: VacuumCommand::VARIANTS,
+    attributes: VacuumAttribute::VARIANTS,
+    options: &[],
+};
+
+static ALARM_CONTROL_PANEL_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::AlarmControlPanel,
+    features: AlarmFeature::VARIANTS,
+    commands: AlarmCommand::VARIANTS,
+    attributes: AlarmAttribute::VARIANTS,
+    options: &[],
+};
+
+static SELECT_CAPS: IntgEntityCaps = IntgEntityCaps {
+    entity_type: EntityType::Select,
+    features: SelectFeature::VARIANTS,
+    commands: SelectCommand::VARIANTS,
+    attributes: SelectAttribute::VARIANTS,
+    options: SelectOptionField::VARIANTS,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MediaPlayerAttribute;
+    use serde_json::json;
+    use std::str::FromStr;
+    use validator::Validate;
+
+    fn select_entity(options: Value) -> AvailableIntgEntity {
+        serde_json::from_value(json!({
+            "entity_id": "select1",
+            "entity_type": "select",
+            "name": { "en": "Mode" },
+            "options": options
+        }))
+        .expect("Invalid json")
+    }
+
+    #[test]
+    fn area_path_validation() {
+        let mut entity = select_entity(json!({ "options": ["A"] }));
+        entity.area_path = Some(vec!["Ground floor".into(), "Living room".into()]);
+        assert!(entity.validate().is_ok());
+
+        entity.area_path = Some(vec!["a".repeat(51)]);
+        assert!(entity.validate().is_err());
+        entity.area_path = Some(vec!["Ground floor".into(), String::new()]);
+        assert!(entity.validate().is_err());
+        entity.area_path = Some(vec!["Room".into(); 11]);
+        assert!(entity.validate().is_err());
+    }
+
+    #[test]
+    fn cover_entity_with_tilt_round_trip() {
+        let json = json!({
+            "entity_id": "blind1",
+            "entity_type": "cover",
+            "device_class": "blind",
+            "name": { "en": "Blind" },
+            "features": ["open", "close", "position", "tilt", "tilt_stop", "tilt_position"],
+            "attributes": { "state": "OPEN", "position": 50, "tilt_position": 30 }
+        });
+        let entity: AvailableIntgEntity = serde_json::from_value(json.clone()).unwrap();
+        let features = entity.features.as_deref().unwrap_or_default();
+        assert!(features
+            .iter()
+            .any(|f| f == CoverFeature::Position.as_ref()));
+        assert!(features
+            .iter()
+            .any(|f| f == CoverFeature::TiltPosition.as_ref()));
+
+        assert_eq!(json, serde_json::to_value(&entity).unwrap());
+    }
+
+    #[test]
+    fn validate_select_entity_options() {
+        assert!(select_entity(json!({ "options": ["eco", "comfort"] }))
+            .validate()
+            .is_ok());
+
+        for options in [
+            json!({}),
+            json!({ "options": [] }),
+            json!({ "options": "eco" }),
+            json!({ "options": ["eco", 1] }),
+        ] {
+            assert!(
+                select_entity(options.clone()).validate().is_err(),
+                "{options}"
+            );
+        }
+    }
+
+    #[test]
+    fn entity_change_with_queue_attributes() {
+        let json = json!({
+            "entity_type": "media_player",
+            "entity_id": "player1",
+            "attributes": {
+                "queue_position": 3,
+                "queue_size": 12
+            }
+        });
+        let change: EntityChange = serde_json::from_value(json).unwrap();
+
+        let mut attributes = change
+            .attributes
+            .iter()
+            .map(|(key, value)| {
+                (
+                    MediaPlayerAttribute::from_str(key).unwrap(),
+                    value.as_u64().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        attributes.sort_by_key(|(_, value)| *value);
+
+        assert_eq!(
+            vec![
+                (MediaPlayerAttribute::QueuePosition, 3),
+                (MediaPlayerAttribute::QueueSize, 12)
+            ],
+            attributes
+        );
+    }
+}
@@ -13,17 +13,20 @@ pub use entity::*;
 use crate::model::intg::{
     IntegrationSetupError, IntegrationSetupState, RequireUserAction, SetupChangeEventType,
 };
+use crate::model::settings::{ConfirmationPage, SettingsPage};
+use crate::util::LanguageMapExt;
 use crate::ws::WsAuthentication;
-use crate::{REGEX_ICON_ID, REGEX_ID_CHARS};
+use crate::{REGEX_DRIVER_URL, REGEX_ICON_ID, REGEX_ID_CHARS};
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
 #[cfg(feature = "sqlx")]
 use sqlx::types::Json;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use strum_macros::*;
-use validator::Validate;
+use validator::{Validate, ValidationError, ValidationErrors};
 
 /// Integration driver version information.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -48,6 +51,27 @@ pub struct SubscribeEvents {
     pub entity_ids: Vec<String>,
 }
 
+/// Language text map of driver and integration names and descriptions.
+///
+/// Key value pairs of language texts. Key: ISO 639-1 code with optional country suffix.
+///
+/// With the `arc-names` feature the map is shared with an [`Arc`](std::sync::Arc) to reduce the
+/// cost of cloning drivers and integrations. The serialized format is the same.
+///
+/// See [`LanguageMapExt`] for language text lookups.
+#[cfg(not(feature = "arc-names"))]
+pub type NameMap = crate::util::LanguageMap;
+/// Language text map of driver and integration names and descriptions.
+///
+/// Key value pairs of language texts. Key: ISO 639-1 code with optional country suffix.
+///
+/// With the `arc-names` feature the map is shared with an [`Arc`](std::sync::Arc) to reduce the
+/// cost of cloning drivers and integrations. The serialized format is the same.
+///
+/// See [`LanguageMapExt`] for language text lookups.
+#[cfg(feature = "arc-names")]
+pub type NameMap = std::sync::Arc<crate::util::LanguageMap>;
+
 /// Integration status information.
 ///
 /// Provides integration instance information.
@@ -60,7 +84,7 @@ pub struct IntegrationStatus {
     pub integration_id: Option<String>,
     /// Name of the integration driver.  
     /// Key value pairs of language texts. Key: ISO 639-1 code with optional country suffix.
-    pub name: HashMap<String, String>,
+    pub name: NameMap,
     /// Optional icon identifier of the integration.
     pub icon: Option<String>,
     pub driver_type: DriverType,
@@ -74,6 +98,32 @@ pub struct IntegrationStatus {
     pub driver_state: Option<DriverState>,
 }
 
+impl IntegrationStatus {
+    /// Create the status information of an integration instance.
+    ///
+    /// The deprecated `device_state` and `driver_state` fields are derived from the given state for
+    /// clients not yet supporting the `state` field. See
+    /// [`IntegrationState::effective_device_state`] and
+    /// [`IntegrationState::effective_driver_state`].
+    pub fn new(
+        driver: &IntegrationDriver,
+        integration: &Integration,
+        state: IntegrationState,
+    ) -> Self {
+        #[allow(deprecated)]
+        Self {
+            driver_id: Some(driver.driver_id.clone()),
+            integration_id: Some(integration.integration_id.clone()),
+            name: integration.name.clone(),
+            icon: integration.icon.clone().or_else(|| driver.icon.clone()),
+            driver_type: driver.driver_type,
+            state: Some(state),
+            device_state: Some(state.effective_device_state()),
+            driver_state: Some(state.effective_driver_state()),
+        }
+    }
+}
+
 /// Minimal integration driver information.
 ///
 /// This data structure is intended for driver overview pages.
@@ -127,6 +177,78 @@ pub struct DriverSetupChange {
     pub require_user_action: Option<RequireUserAction>,
 }
 
+impl DriverSetupChange {
+    /// Create a setup start event.
+    pub fn start() -> Self {
+        Self::new(SetupChangeEventType::Start, IntegrationSetupState::Setup)
+    }
+
+    /// Create a setup progress event.
+    pub fn in_progress() -> Self {
+        Self::new(SetupChangeEventType::Setup, IntegrationSetupState::Setup)
+    }
+
+    /// Create a setup event requesting user input of the given settings page.
+    pub fn wait_for_input(page: SettingsPage) -> Self {
+        Self {
+            require_user_action: Some(RequireUserAction::Input(page)),
+            ..Self::new(
+                SetupChangeEventType::Setup,
+                IntegrationSetupState::WaitUserAction,
+            )
+        }
+    }
+
+    /// Create a setup event requesting a user confirmation of the given page.
+    pub fn wait_for_confirmation(page: ConfirmationPage) -> Self {
+        Self {
+            require_user_action: Some(RequireUserAction::Confirmation(page)),
+            ..Self::new(
+                SetupChangeEventType::Setup,
+                IntegrationSetupState::WaitUserAction,
+            )
+        }
+    }
+
+    /// Create a setup stop event for a successfully finished setup.
+    pub fn complete() -> Self {
+        Self::new(SetupChangeEventType::Stop, IntegrationSetupState::Ok)
+    }
+
+    /// Create a setup stop event for a failed setup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uc_api::intg::DriverSetupChange;
+    /// use uc_api::model::intg::IntegrationSetupError;
+    /// let event = DriverSetupChange::fail(IntegrationSetupError::Timeout);
+    /// assert_eq!(serde_json::json!({
+    ///     "event_type": "STOP",
+    ///     "state": "ERROR",
+    ///     "error": "TIMEOUT"
+    /// }), serde_json::to_value(event).unwrap());
+    /// ```
+    pub fn fail(error: IntegrationSetupError) -> Self {
+        Self::new(SetupChangeEventType::Stop, IntegrationSetupState::Error).with_error(error)
+    }
+
+    /// Set the detailed error reason.
+    pub fn with_error(mut self, error: IntegrationSetupError) -> Self {
+        self.error = Some(error);
+        self
+    }
+
+    fn new(event_type: SetupChangeEventType, state: IntegrationSetupState) -> Self {
+        Self {
+            event_type,
+            state,
+            error: None,
+            require_user_action: None,
+        }
+    }
+}
+
 /// Message data payload of `set_driver_user_data`
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -142,6 +264,95 @@ pub enum IntegrationSetup {
     Confirm(bool),
 }
 
+impl IntegrationSetup {
+    /// Get a user provided input value.
+    ///
+    /// Returns `None` if the value is not provided or for a user confirmation.
+    pub fn get_input_value(&self, key: &str) -> Option<&str> {
+        match self {
+            IntegrationSetup::InputValues(values) => values.get(key).map(|v| v.as_str()),
+            IntegrationSetup::Confirm(_) => None,
+        }
+    }
+
+    /// Get a required user provided input value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use uc_api::intg::{IntegrationSetup, SetupInputError};
+    /// let setup = IntegrationSetup::InputValues(HashMap::from([("host".into(), "192.168.1.2".into())]));
+    /// assert_eq!(Ok("192.168.1.2"), setup.input_value_required("host"));
+    /// assert_eq!(Err(SetupInputError::MissingValue("port".into())), setup.input_value_required("port"));
+    /// assert_eq!(Err(SetupInputError::NoInputValues), IntegrationSetup::Confirm(true).input_value_required("host"));
+    /// ```
+    pub fn input_value_required(&self, key: &str) -> Result<&str, SetupInputError> {
+        match self {
+            IntegrationSetup::InputValues(values) => values
+                .get(key)
+                .map(|v| v.as_str())
+                .ok_or_else(|| SetupInputError::MissingValue(key.to_string())),
+            IntegrationSetup::Confirm(_) => Err(SetupInputError::NoInputValues),
+        }
+    }
+
+    /// Convert the setup data into the user provided input values.
+    ///
+    /// Returns the original setup data as error for a user confirmation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use uc_api::intg::IntegrationSetup;
+    /// let setup = IntegrationSetup::InputValues(HashMap::from([("host".into(), "192.168.1.2".into())]));
+    /// let values = setup.into_input_values().unwrap();
+    /// assert_eq!(Some("192.168.1.2"), values.get("host").map(|v| v.as_str()));
+    /// assert!(IntegrationSetup::Confirm(true).into_input_values().is_err());
+    /// ```
+    pub fn into_input_values(self) -> Result<HashMap<String, String>, IntegrationSetup> {
+        match self {
+            IntegrationSetup::InputValues(values) => Ok(values),
+            IntegrationSetup::Confirm(_) => Err(self),
+        }
+    }
+
+    /// Convert the setup data into the user provided input values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the setup data is a user confirmation.
+    pub fn unwrap_input_values(self) -> HashMap<String, String> {
+        match self {
+            IntegrationSetup::InputValues(values) => values,
+            IntegrationSetup::Confirm(_) => {
+                panic!("called `IntegrationSetup::unwrap_input_values()` on a `Confirm` value")
+            }
+        }
+    }
+}
+
+/// Error retrieving a user provided input value from [`IntegrationSetup`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SetupInputError {
+    /// The setup data is a user confirmation and doesn't contain input values.
+    NoInputValues,
+    /// The required input value with the contained identifier is missing.
+    MissingValue(String),
+}
+
+impl std::fmt::Display for SetupInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SetupInputError::NoInputValues => write!(f, "No input values provided"),
+            SetupInputError::MissingValue(key) => write!(f, "Missing input value: {key}"),
+        }
+    }
+}
+
+impl std::error::Error for SetupInputError {}
+
 /// Integration driver model.
 ///
 /// A driver represents the communication aspect of an integration. E.g. how one can connect to it
@@ -165,7 +376,7 @@ pub struct IntegrationDriver {
     /// represent a `culture code`. Examples: `en`, `en-UK`, `en-US`, `de`, `de-CH`.  
     /// An english text with key `en` should always be provided as fallback option. Otherwise it's
     /// not guaranteed which text will be displayed if the user selected language is not provided.
-    pub name: HashMap<String, String>,
+    pub name: NameMap,
     pub driver_type: DriverType,
     /// WebSocket URL of the integration driver.
     pub driver_url: String,
@@ -190,7 +401,7 @@ pub struct IntegrationDriver {
     pub enabled: bool,
     /// Optional description of the integration.  
     /// Key value pairs of language texts.
-    pub description: Option<HashMap<String, String>>,
+    pub description: Option<NameMap>,
     /// Optional information about the integration developer or company.
     pub developer: Option<DriverDeveloper>,
     /// Optional home page url for more information.
@@ -210,6 +421,513 @@ pub struct IntegrationDriver {
     pub driver_state: Option<DriverState>,
 }
 
+impl IntegrationDriver {
+    /// Create a builder for a new integration driver.
+    ///
+    /// See [`IntegrationDriverBuilder`] for the default values.
+    pub fn builder() -> IntegrationDriverBuilder {
+        IntegrationDriverBuilder::default()
+    }
+
+    /// Get the authentication method to use when connecting to the driver.
+    ///
+    /// If a token is set without an explicit authentication method, the token is sent in the
+    /// connection header.
+    pub fn effective_auth_method(&self) -> Option<WsAuthentication> {
+        self.auth_method
+            .or_else(|| self.token.as_ref().map(|_| WsAuthentication::Header))
+    }
+
+    /// Get the parameters to establish a WebSocket connection to the driver.
+    ///
+    /// Returns `None` if the driver is disabled or the driver URL is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use uc_api::intg::IntegrationDriver;
+    /// use uc_api::ws::WsAuthentication;
+    /// let driver = IntegrationDriver::builder()
+    ///     .driver_id("test")
+    ///     .name(HashMap::from([("en".into(), "Test".into())]))
+    ///     .driver_url("ws://localhost:9090")
+    ///     .token("secret")
+    ///     .version("1.0.0")
+    ///     .build()
+    ///     .unwrap();
+    /// let params = driver.connection_params().unwrap();
+    /// assert_eq!("ws://localhost:9090/", params.url.as_str());
+    /// assert_eq!(Some("secret"), params.token.as_deref());
+    /// assert_eq!(WsAuthentication::Header, params.auth_method);
+    /// ```
+    pub fn connection_params(&self) -> Option<ConnectionParams> {
+        if !self.enabled {
+            return None;
+        }
+        let url = url::Url::parse(&self.driver_url).ok()?;
+        Some(ConnectionParams {
+            url,
+            token: self.token.clone(),
+            auth_method: self
+                .effective_auth_method()
+                .unwrap_or(WsAuthentication::Header),
+        })
+    }
+
+    /// Remove the authentication token before returning the driver to external clients.
+    ///
+    /// `pwd_protected` is set if a token was present, otherwise it is not changed.
+    pub fn redact(&mut self) {
+        if self.token.take().is_some() {
+            self.pwd_protected = Some(true);
+        }
+    }
+
+    /// Owned version of [`redact`](Self::redact), e.g. for serializing a response message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use uc_api::intg::IntegrationDriver;
+    /// let driver = IntegrationDriver::builder()
+    ///     .driver_id("test")
+    ///     .name(HashMap::from([("en".into(), "Test".into())]))
+    ///     .driver_url("ws://localhost:9090")
+    ///     .token("secret")
+    ///     .version("1.0.0")
+    ///     .build()
+    ///     .unwrap();
+    /// let driver = driver.redacted();
+    /// assert_eq!(None, driver.token);
+    /// assert_eq!(Some(true), driver.pwd_protected);
+    /// ```
+    pub fn redacted(mut self) -> Self {
+        self.redact();
+        self
+    }
+
+    /// Format the driver information for logging, without exposing secrets.
+    ///
+    /// The authentication token is replaced with `<set>` or `<not set>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use uc_api::intg::IntegrationDriver;
+    /// let driver = IntegrationDriver::builder()
+    ///     .driver_id("test")
+    ///     .name(HashMap::from([("en".into(), "Test".into())]))
+    ///     .driver_url("ws://localhost:9090")
+    ///     .token("secret")
+    ///     .version("1.0.0")
+    ///     .build()
+    ///     .unwrap();
+    /// let log = driver.to_safe_log_string();
+    /// assert!(log.contains("token: <set>"));
+    /// assert!(!log.contains("secret"));
+    /// ```
+    pub fn to_safe_log_string(&self) -> String {
+        format!(
+            "driver_id: {}, version: {}, driver_type: {}, driver_url: {}, token: {}, auth_method: {}, pwd_protected: {}, enabled: {}",
+            self.driver_id,
+            self.version,
+            self.driver_type,
+            self.driver_url,
+            if self.token.is_some() { "<set>" } else { "<not set>" },
+            self.auth_method.map(|m| m.to_string()).unwrap_or_else(|| "-".into()),
+            self.pwd_protected.map(|p| p.to_string()).unwrap_or_else(|| "-".into()),
+            self.enabled
+        )
+    }
+
+    /// Check if the driver has the given driver identifier.
+    ///
+    /// Driver identifiers are case-sensitive: identifiers only differing in case refer to
+    /// different drivers. This is the same rule as the equality of [`DriverId`](crate::DriverId)
+    /// and the identifier matching of [`AvailableEntitiesFilter`](ws::AvailableEntitiesFilter).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use uc_api::intg::IntegrationDriver;
+    /// let driver = IntegrationDriver::builder()
+    ///     .driver_id("my-driver")
+    ///     .name(HashMap::from([("en".into(), "Test".into())]))
+    ///     .driver_url("ws://localhost:9090")
+    ///     .version("1.0.0")
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(driver.matches_driver_id("my-driver"));
+    /// assert!(!driver.matches_driver_id("My-Driver"));
+    /// ```
+    pub fn matches_driver_id(&self, id: &str) -> bool {
+        self.driver_id == id
+    }
+
+    /// Check if the given core API version fulfills the minimum required core API version of the
+    /// driver.
+    ///
+    /// Returns `None` if the driver doesn't specify a minimum version or if one of the versions
+    /// cannot be parsed, `Some(true)` if `current_version` is equal or newer than `min_core_api`,
+    /// `Some(false)` otherwise.
+    ///
+    /// With the `semver` feature the versions are compared according to the SemVer
+    /// specification. Otherwise, or if a version is not a valid SemVer version, a simplified
+    /// comparison of the dot separated numeric parts is used: missing parts count as `0`, a
+    /// pre-release version ranks below its release and build metadata is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use uc_api::intg::IntegrationDriver;
+    /// let driver = IntegrationDriver::builder()
+    ///     .driver_id("test")
+    ///     .name(HashMap::from([("en".into(), "Test".into())]))
+    ///     .driver_url("ws://localhost:9090")
+    ///     .version("1.0.0")
+    ///     .min_core_api("0.20.0")
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(Some(true), driver.requires_min_core_api_version("0.20.0"));
+    /// assert_eq!(Some(true), driver.requires_min_core_api_version("0.100.1"));
+    /// assert_eq!(Some(false), driver.requires_min_core_api_version("0.9.5"));
+    /// assert_eq!(Some(false), driver.requires_min_core_api_version("0.20.0-beta"));
+    /// assert_eq!(None, driver.requires_min_core_api_version("latest"));
+    /// ```
+    pub fn requires_min_core_api_version(&self, current_version: &str) -> Option<bool> {
+        self.min_core_api
+            .as_deref()
+            .and_then(|min_version| version_at_least(current_version, min_version))
+    }
+}
+
+/// Parameters to establish a WebSocket connection to an integration driver.
+///
+/// See [`IntegrationDriver::connection_params`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionParams {
+    /// WebSocket URL of the integration driver.
+    pub url: url::Url,
+    /// Optional driver authentication token.
+    pub token: Option<String>,
+    /// Authentication method to use if a token is set.
+    pub auth_method: WsAuthentication,
+}
+
+/// Short driver summary for logging purposes: `{name} ({driver_id}) v{version} [{driver_type}]`.
+///
+/// The English name is used, the driver identifier if the name map is empty.
+impl std::fmt::Display for IntegrationDriver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = self.name.get_for_lang("en").unwrap_or(&self.driver_id);
+        write!(
+            f,
+            "{name} ({}) v{} [{}]",
+            self.driver_id, self.version, self.driver_type
+        )
+    }
+}
+
+/// Builder for an [`IntegrationDriver`].
+///
+/// The required fields `driver_id`, `name`, `driver_url` and `version` must be set before calling
+/// [`build`](Self::build). Defaults of the other fields:
+/// - `driver_type`: `External`
+/// - `enabled`: `true`
+/// - `device_discovery`: `false`
+/// - `setup_data_schema`: empty JSON object
+/// - all optional fields: `None`
+///
+/// An existing driver can be converted into a builder to create a modified copy.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use uc_api::intg::{IntegrationDriver, IntegrationDriverBuilder};
+/// let driver = IntegrationDriver::builder()
+///     .driver_id("test")
+///     .name(HashMap::from([("en".into(), "Test".into())]))
+///     .driver_url("ws://localhost:9090")
+///     .version("1.0.0")
+///     .build()
+///     .unwrap();
+/// assert!(driver.enabled);
+///
+/// let driver = IntegrationDriverBuilder::from(driver)
+///     .version("1.1.0")
+///     .build()
+///     .unwrap();
+/// assert_eq!("1.1.0", driver.version);
+///
+/// assert!(IntegrationDriver::builder().driver_id("test").build().is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntegrationDriverBuilder {
+    driver: IntegrationDriver,
+}
+
+impl Default for IntegrationDriverBuilder {
+    fn default() -> Self {
+        Self {
+            driver: IntegrationDriver {
+                driver_id: String::new(),
+                name: Default::default(),
+                driver_type: DriverType::External,
+                driver_url: String::new(),
+                token: None,
+                auth_method: None,
+                pwd_protected: None,
+                version: String::new(),
+                min_core_api: None,
+                icon: None,
+                enabled: true,
+                description: None,
+                developer: None,
+                home_page: None,
+                device_discovery: false,
+                instance_count: None,
+                setup_data_schema: empty_setup_data_schema(),
+                release_date: None,
+                driver_state: None,
+            },
+        }
+    }
+}
+
+impl From<IntegrationDriver> for IntegrationDriverBuilder {
+    fn from(driver: IntegrationDriver) -> Self {
+        Self { driver }
+    }
+}
+
+impl IntegrationDriverBuilder {
+    /// Set the unique driver identifier. Required.
+    pub fn driver_id(mut self, driver_id: impl Into<String>) -> Self {
+        self.driver.driver_id = driver_id.into();
+        self
+    }
+
+    /// Set the language text map of the driver name.
+    pub fn name(mut self, name: HashMap<String, String>) -> Self {
+        self.driver.name = shared_name_map(name);
+        self
+    }
+
+    /// Set the driver type. Defaults to `External`.
+    pub fn driver_type(mut self, driver_type: DriverType) -> Self {
+        self.driver.driver_type = driver_type;
+        self
+    }
+
+    /// Set the WebSocket URL of the driver. Required, must be a `ws://` or `wss://` URL.
+    pub fn driver_url(mut self, driver_url: impl Into<String>) -> Self {
+        self.driver.driver_url = driver_url.into();
+        self
+    }
+
+    /// Set the optional authentication token to connect to the driver.
+    pub fn token(mut self, token: impl Into<String>) -> Self {
+        self.driver.token = Some(token.into());
+        self
+    }
+
+    /// Set the authentication method if a token is used.
+    pub fn auth_method(mut self, auth_method: WsAuthentication) -> Self {
+        self.driver.auth_method = Some(auth_method);
+        self
+    }
+
+    /// Set if the driver requires a connection password.
+    pub fn pwd_protected(mut self, pwd_protected: bool) -> Self {
+        self.driver.pwd_protected = Some(pwd_protected);
+        self
+    }
+
+    /// Set the driver version. Required, [SemVer](https://semver.org/) preferred.
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.driver.version = version.into();
+        self
+    }
+
+    /// Set the minimum required core API version.
+    pub fn min_core_api(mut self, min_core_api: impl Into<String>) -> Self {
+        self.driver.min_core_api = Some(min_core_api.into());
+        self
+    }
+
+    /// Set the icon identifier of the driver.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.driver.icon = Some(icon.into());
+        self
+    }
+
+    /// Enable or disable driver communication. Defaults to `true`.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.driver.enabled = enabled;
+        self
+    }
+
+    /// Set the language text map of the driver description.
+    pub fn description(mut self, description: HashMap<String, String>) -> Self {
+        self.driver.description = Some(shared_name_map(description));
+        self
+    }
+
+    /// Set the information about the driver developer or company.
+    pub fn developer(mut self, developer: DriverDeveloper) -> Self {
+        self.driver.developer = Some(developer);
+        self
+    }
+
+    /// Set the home page url for more information.
+    pub fn home_page(mut self, home_page: impl Into<String>) -> Self {
+        self.driver.home_page = Some(home_page.into());
+        self
+    }
+
+    /// Set if the driver supports multi-device discovery. Defaults to `false`.
+    pub fn device_discovery(mut self, device_discovery: bool) -> Self {
+        self.driver.device_discovery = device_discovery;
+        self
+    }
+
+    /// Set the number of integration instances.
+    pub fn instance_count(mut self, instance_count: u16) -> Self {
+        self.driver.instance_count = Some(instance_count);
+        self
+    }
+
+    /// Set the driver configuration metadata for the web-configurator. Defaults to an empty JSON
+    /// object.
+    pub fn setup_data_schema(mut self, setup_data_schema: Value) -> Self {
+        #[cfg(feature = "sqlx")]
+        let setup_data_schema = Json(setup_data_schema);
+        self.driver.setup_data_schema = setup_data_schema;
+        self
+    }
+
+    /// Set the release date of the driver.
+    pub fn release_date(mut self, release_date: NaiveDate) -> Self {
+        self.driver.release_date = Some(release_date);
+        self
+    }
+
+    /// Set the current driver state.
+    pub fn driver_state(mut self, driver_state: DriverState) -> Self {
+        self.driver.driver_state = Some(driver_state);
+        self
+    }
+
+    /// Create the integration driver.
+    ///
+    /// # Errors
+    ///
+    /// A message describing the first invalid field if `driver_id`, `name` or `version` are
+    /// empty, or `driver_url` is not a valid WebSocket URL.
+    pub fn build(self) -> Result<IntegrationDriver, String> {
+        let driver = self.driver;
+        if driver.driver_id.is_empty() {
+            return Err("Missing required field: driver_id".into());
+        }
+        if driver.name.is_empty() {
+            return Err("Missing required field: name".into());
+        }
+        if !REGEX_DRIVER_URL.is_match(&driver.driver_url) {
+            return Err(format!("Invalid driver_url: {}", driver.driver_url));
+        }
+        if driver.version.is_empty() {
+            return Err("Missing required field: version".into());
+        }
+        Ok(driver)
+    }
+}
+
+/// Check if `version` is equal or newer than `min_version`.
+///
+/// Returns `None` if one of the versions cannot be parsed.
+fn version_at_least(version: &str, min_version: &str) -> Option<bool> {
+    #[cfg(feature = "semver")]
+    if let (Ok(mut version), Ok(mut min_version)) = (
+        semver::Version::parse(version),
+        semver::Version::parse(min_version),
+    ) {
+        // build metadata must be ignored when determining version precedence
+        version.build = semver::BuildMetadata::EMPTY;
+        min_version.build = semver::BuildMetadata::EMPTY;
+        return Some(version >= min_version);
+    }
+
+    simple_version_at_least(version, min_version)
+}
+
+/// Simplified version comparison without the `semver` feature or for non-SemVer versions like
+/// `2.0`.
+///
+/// The dot separated numeric parts are compared, missing parts count as `0`. With equal numeric
+/// parts a pre-release version ranks below its release, pre-release identifiers are compared
+/// according to the SemVer precedence rules. Build metadata is ignored.
+fn simple_version_at_least(version: &str, min_version: &str) -> Option<bool> {
+    // numeric parts and optional pre-release
+    let parse = |v: &str| -> Option<(Vec<u64>, Option<String>)> {
+        let v = v.split('+').next().unwrap_or_default();
+        let (core, pre_release) = match v.split_once('-') {
+            Some((_, "")) => return None,
+            Some((core, pre_release)) => (core, Some(pre_release.to_string())),
+            None => (v, None),
+        };
+        let parts = core
+            .split('.')
+            .map(|part| part.parse().ok())
+            .collect::<Option<Vec<u64>>>()?;
+        Some((parts, pre_release))
+    };
+
+    let (mut version, version_pre) = parse(version)?;
+    let (mut min_version, min_version_pre) = parse(min_version)?;
+    let len = version.len().max(min_version.len());
+    version.resize(len, 0);
+    min_version.resize(len, 0);
+
+    let ordering = version
+        .cmp(&min_version)
+        .then_with(|| match (version_pre, min_version_pre) {
+            (None, None) => Ordering::Equal,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(pre), Some(min_pre)) => compare_pre_release(&pre, &min_pre),
+        });
+    Some(ordering.is_ge())
+}
+
+/// Compare pre-release versions: numeric identifiers are compared numerically and rank below
+/// alphanumeric identifiers, a larger set of identifiers ranks above a smaller set.
+fn compare_pre_release(pre: &str, other: &str) -> Ordering {
+    let mut identifiers = pre.split('.');
+    let mut other_identifiers = other.split('.');
+    loop {
+        let ordering = match (identifiers.next(), other_identifiers.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(id), Some(other_id)) => match (id.parse::<u64>(), other_id.parse::<u64>()) {
+                (Ok(id), Ok(other_id)) => id.cmp(&other_id),
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+                (Err(_), Err(_)) => id.cmp(other_id),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
 /// Integration driver update model.
 ///
 /// This is a dedicated model related to [`IntegrationDriver`] for create and patch update
@@ -219,26 +937,40 @@ pub struct IntegrationDriver {
 #[derive(Debug, Clone, Deserialize, Serialize, Validate)]
 pub struct IntegrationDriverUpdate {
     /// Integration driver identifier.  
+    /// Required when creating a new driver. [`generate_id`](crate::util::generate_id) can be
+    /// used to assign an identifier if the driver doesn't provide one.
     #[validate(length(max = 36, message = "Invalid length (max = 36)"))]
     #[validate(regex(path = "REGEX_ID_CHARS"))]
     pub driver_id: Option<String>,
-    // TODO validate HashMap with custom validation function?
+    /// Name of the driver. An English text is required as fallback option.
+    #[validate(custom = "validate_language_texts")]
     pub name: Option<HashMap<String, String>>,
-    #[validate(url)]
+    /// WebSocket URL of the integration driver: `ws://` or `wss://` scheme.
+    #[validate(regex(
+        path = "REGEX_DRIVER_URL",
+        code = "INVALID_URL",
+        message = "Invalid WebSocket URL (ws:// or wss:// required)"
+    ))]
     #[validate(length(max = 2048, message = "Invalid length (max = 2048)"))]
     pub driver_url: Option<String>,
     #[validate(length(max = 2048, message = "Invalid length (max = 2048)"))]
     pub token: Option<String>,
     pub auth_method: Option<WsAuthentication>,
     pub pwd_protected: Option<bool>,
+    /// Driver version in [SemVer](https://semver.org/) format.
     #[validate(length(max = 20, message = "Invalid length (max = 20)"))]
+    #[validate(custom = "validate_semver")]
     pub version: Option<String>,
+    /// Minimum required core API version in [SemVer](https://semver.org/) format.
     #[validate(length(max = 20, message = "Invalid length (max = 20)"))]
+    #[validate(custom = "validate_semver")]
     pub min_core_api: Option<String>,
     /// Optional icon identifier of the integration driver.
     #[validate(length(max = 255, message = "Invalid length (max = 255)"))]
     pub icon: Option<String>,
     pub enabled: Option<bool>,
+    /// Optional description of the driver. An English text is required as fallback option.
+    #[validate(custom = "validate_language_texts")]
     pub description: Option<HashMap<String, String>>,
     #[validate]
     pub developer: Option<DriverDeveloper>,
@@ -253,6 +985,95 @@ pub struct IntegrationDriverUpdate {
     pub release_date: Option<NaiveDate>,
 }
 
+impl IntegrationDriverUpdate {
+    /// Apply the set fields of this patch update to the given driver.
+    ///
+    /// The update model is validated first and the driver is only modified if it is valid. Fields
+    /// which are not set in the update are not changed. The driver identifier cannot be changed:
+    /// a different `driver_id` in the update is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use serde_json::json;
+    /// use uc_api::intg::{IntegrationDriver, IntegrationDriverUpdate};
+    /// let mut driver = IntegrationDriver::builder()
+    ///     .driver_id("test")
+    ///     .name(HashMap::from([("en".into(), "Test".into())]))
+    ///     .driver_url("ws://localhost:9090")
+    ///     .version("1.0.0")
+    ///     .build()
+    ///     .unwrap();
+    /// let update: IntegrationDriverUpdate =
+    ///     serde_json::from_value(json!({ "version": "1.1.0", "enabled": false })).unwrap();
+    /// update.apply_to(&mut driver).unwrap();
+    /// assert_eq!("1.1.0", driver.version);
+    /// assert!(!driver.enabled);
+    /// assert_eq!("ws://localhost:9090", driver.driver_url);
+    /// ```
+    pub fn apply_to(self, driver: &mut IntegrationDriver) -> Result<(), ValidationErrors> {
+        self.validate()?;
+
+        if let Some(name) = self.name {
+            driver.name = shared_name_map(name);
+        }
+        if let Some(driver_url) = self.driver_url {
+            driver.driver_url = driver_url;
+        }
+        if self.token.is_some() {
+            driver.token = self.token;
+        }
+        if self.auth_method.is_some() {
+            driver.auth_method = self.auth_method;
+        }
+        if self.pwd_protected.is_some() {
+            driver.pwd_protected = self.pwd_protected;
+        }
+        if let Some(version) = self.version {
+            driver.version = version;
+        }
+        if self.min_core_api.is_some() {
+            driver.min_core_api = self.min_core_api;
+        }
+        if self.icon.is_some() {
+            driver.icon = self.icon;
+        }
+        if let Some(enabled) = self.enabled {
+            driver.enabled = enabled;
+        }
+        if let Some(description) = self.description {
+            driver.description = Some(shared_name_map(description));
+        }
+        if self.developer.is_some() {
+            driver.developer = self.developer;
+        }
+        if self.home_page.is_some() {
+            driver.home_page = self.home_page;
+        }
+        if let Some(device_discovery) = self.device_discovery {
+            driver.device_discovery = device_discovery;
+        }
+        if let Some(setup_data_schema) = self.setup_data_schema {
+            driver.setup_data_schema = setup_data_schema;
+        }
+        if self.release_date.is_some() {
+            driver.release_date = self.release_date;
+        }
+
+        Ok(())
+    }
+
+    /// Owned version of [`apply_to`](Self::apply_to): returns the updated driver.
+    pub fn applied(
+        self,
+        mut driver: IntegrationDriver,
+    ) -> Result<IntegrationDriver, ValidationErrors> {
+        self.apply_to(&mut driver)?;
+        Ok(driver)
+    }
+}
+
 /// Integration driver type.
 ///
 /// Variants will be serialized in `SCREAMING_SNAKE_CASE`.
@@ -270,6 +1091,18 @@ pub enum DriverType {
     Custom,
 }
 
+impl DriverType {
+    /// Returns true for pre-installed integrations, which are managed by the firmware.
+    pub fn is_managed_by_firmware(&self) -> bool {
+        matches!(self, DriverType::Local)
+    }
+
+    /// Returns true for custom integrations, which can be installed by the user on the remote.
+    pub fn is_user_installable(&self) -> bool {
+        matches!(self, DriverType::Custom)
+    }
+}
+
 /// Developer information for an integration driver.
 #[skip_serializing_none]
 #[derive(Debug, Clone, Deserialize, Serialize, Validate)]
@@ -284,11 +1117,49 @@ pub struct DriverDeveloper {
     pub email: Option<String>,
 }
 
+impl DriverDeveloper {
+    /// Get the developer contact information for display purposes.
+    ///
+    /// Format: `name <email> (url)`, fields which are not set are omitted. Returns an empty string
+    /// if no information is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uc_api::intg::DriverDeveloper;
+    /// let developer = DriverDeveloper {
+    ///     name: Some("Alice".into()),
+    ///     url: Some("https://example.com".into()),
+    ///     email: Some("alice@example.com".into()),
+    /// };
+    /// assert_eq!("Alice <alice@example.com> (https://example.com)", developer.display_contact());
+    /// let developer = DriverDeveloper {
+    ///     name: None,
+    ///     url: Some("https://example.com".into()),
+    ///     email: None,
+    /// };
+    /// assert_eq!("(https://example.com)", developer.display_contact());
+    /// ```
+    pub fn display_contact(&self) -> String {
+        let mut parts = Vec::with_capacity(3);
+        if let Some(name) = &self.name {
+            parts.push(name.clone());
+        }
+        if let Some(email) = &self.email {
+            parts.push(format!("<{email}>"));
+        }
+        if let Some(url) = &self.url {
+            parts.push(format!("({url})"));
+        }
+        parts.join(" ")
+    }
+}
+
 impl From<IntegrationDriver> for IntegrationDriverUpdate {
     fn from(drv: IntegrationDriver) -> Self {
         Self {
             driver_id: Some(drv.driver_id),
-            name: Some(drv.name),
+            name: Some(owned_name_map(drv.name)),
             driver_url: Some(drv.driver_url),
             token: drv.token,
             auth_method: drv.auth_method,
@@ -297,7 +1168,7 @@ impl From<IntegrationDriver> for IntegrationDriverUpdate {
             min_core_api: drv.min_core_api,
             icon: drv.icon,
             enabled: Some(drv.enabled),
-            description: drv.description,
+            description: drv.description.map(owned_name_map),
             developer: drv.developer,
             home_page: drv.home_page,
             device_discovery: Some(drv.device_discovery),
@@ -307,6 +1178,120 @@ impl From<IntegrationDriver> for IntegrationDriverUpdate {
     }
 }
 
+impl TryFrom<IntegrationDriverUpdate> for IntegrationDriver {
+    type Error = ValidationErrors;
+
+    /// Create a new integration driver from an update model, e.g. for a driver registration.
+    ///
+    /// The update model is validated and must contain the required fields `driver_id`, `name`,
+    /// `driver_url` and `version`. Missing optional flags default to an enabled driver without
+    /// device discovery.
+    ///
+    /// The created driver is always of type `External`: the update model is only used to register
+    /// network drivers through the API. `Local` drivers are pre-installed in the firmware and
+    /// `Custom` drivers are created by the driver installation on the remote, neither is
+    /// registered with an update model.
+    fn try_from(update: IntegrationDriverUpdate) -> Result<Self, Self::Error> {
+        let mut errors = match update.validate() {
+            Ok(_) => ValidationErrors::new(),
+            Err(e) => e,
+        };
+        for (field, missing) in [
+            ("driver_id", update.driver_id.is_none()),
+            ("name", update.name.is_none()),
+            ("driver_url", update.driver_url.is_none()),
+            ("version", update.version.is_none()),
+        ] {
+            if missing {
+                errors.add(field, ValidationError::new("required"));
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(Self {
+            driver_id: update.driver_id.unwrap_or_default(),
+            name: shared_name_map(update.name.unwrap_or_default()),
+            driver_type: DriverType::External,
+            driver_url: update.driver_url.unwrap_or_default(),
+            token: update.token,
+            auth_method: update.auth_method,
+            pwd_protected: update.pwd_protected,
+            version: update.version.unwrap_or_default(),
+            min_core_api: update.min_core_api,
+            icon: update.icon,
+            enabled: update.enabled.unwrap_or(true),
+            description: update.description.map(shared_name_map),
+            developer: update.developer,
+            home_page: update.home_page,
+            device_discovery: update.device_discovery.unwrap_or_default(),
+            instance_count: None,
+            setup_data_schema: update
+                .setup_data_schema
+                .unwrap_or_else(empty_setup_data_schema),
+            release_date: update.release_date,
+            driver_state: None,
+        })
+    }
+}
+
+/// Language text maps require an English fallback text.
+fn validate_language_texts(map: &HashMap<String, String>) -> Result<(), ValidationError> {
+    map.validate_has_fallback().map_err(|msg| {
+        let mut error = ValidationError::new("MISSING_EN");
+        error.message = Some(msg.into());
+        error
+    })
+}
+
+/// Versions must be in `major.minor.patch` format, see [`crate::util::is_semver`].
+fn validate_semver(version: &str) -> Result<(), ValidationError> {
+    if crate::util::is_semver(version) {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("INVALID_VERSION");
+        error.message = Some("Invalid SemVer version (major.minor.patch required)".into());
+        Err(error)
+    }
+}
+
+/// Empty driver configuration metadata.
+#[cfg(feature = "sqlx")]
+fn empty_setup_data_schema() -> Json<Value> {
+    Json(Value::Object(Default::default()))
+}
+
+/// Empty driver configuration metadata.
+#[cfg(not(feature = "sqlx"))]
+fn empty_setup_data_schema() -> Value {
+    Value::Object(Default::default())
+}
+
+/// Convert an update model language text map into a [`NameMap`].
+#[cfg(feature = "arc-names")]
+fn shared_name_map(map: HashMap<String, String>) -> NameMap {
+    std::sync::Arc::new(map)
+}
+
+/// Convert an update model language text map into a [`NameMap`].
+#[cfg(not(feature = "arc-names"))]
+fn shared_name_map(map: HashMap<String, String>) -> NameMap {
+    map
+}
+
+/// Convert a [`NameMap`] into an owned language text map of an update model.
+#[cfg(feature = "arc-names")]
+fn owned_name_map(map: NameMap) -> HashMap<String, String> {
+    std::sync::Arc::try_unwrap(map).unwrap_or_else(|map| (*map).clone())
+}
+
+/// Convert a [`NameMap`] into an owned language text map of an update model.
+#[cfg(not(feature = "arc-names"))]
+fn owned_name_map(map: NameMap) -> HashMap<String, String> {
+    map
+}
+
 /// Integration instance model.
 ///
 /// An integration instance represents a configured integration driver.
@@ -323,7 +1308,7 @@ pub struct Integration {
     /// Name of the integration instance.  
     /// Usually the default driver name and an optional device identifier.  
     /// Key value pairs of language texts. Key: ISO 639-1 code with optional country suffix.
-    pub name: HashMap<String, String>,
+    pub name: NameMap,
     /// Optional icon identifier of the integration.
     pub icon: Option<String>,
     pub enabled: bool,
@@ -367,13 +1352,74 @@ pub struct IntegrationUpdate {
     pub setup_data: Option<serde_json::Map<String, Value>>,
 }
 
+impl Integration {
+    /// Get a value of the configuration data.
+    pub fn setup_data_value(&self, key: &str) -> Option<&Value> {
+        self.setup_data.get(key)
+    }
+
+    /// Get a string value of the configuration data.
+    pub fn setup_data_str(&self, key: &str) -> Option<&str> {
+        self.setup_data_value(key).and_then(|v| v.as_str())
+    }
+
+    /// Get a boolean value of the configuration data.
+    pub fn setup_data_bool(&self, key: &str) -> Option<bool> {
+        self.setup_data_value(key).and_then(|v| v.as_bool())
+    }
+
+    /// Get an unsigned integer value of the configuration data.
+    pub fn setup_data_u64(&self, key: &str) -> Option<u64> {
+        self.setup_data_value(key).and_then(|v| v.as_u64())
+    }
+}
+
+impl IntegrationUpdate {
+    /// Returns true if the update enables or disables the integration.
+    ///
+    /// An enabled state change requires a reconnection of the integration.
+    pub fn is_enable_change(&self) -> bool {
+        self.enabled.is_some()
+    }
+
+    /// Returns true if the update changes the integration name.
+    pub fn is_name_change(&self) -> bool {
+        self.name.is_some()
+    }
+
+    /// Returns true if the update changes the integration configuration data.
+    pub fn is_setup_data_change(&self) -> bool {
+        self.setup_data.is_some()
+    }
+
+    /// Get a value of the configuration data, if provided in the update.
+    pub fn setup_data_value(&self, key: &str) -> Option<&Value> {
+        self.setup_data.as_ref().and_then(|data| data.get(key))
+    }
+
+    /// Get a string value of the configuration data, if provided in the update.
+    pub fn setup_data_str(&self, key: &str) -> Option<&str> {
+        self.setup_data_value(key).and_then(|v| v.as_str())
+    }
+
+    /// Get a boolean value of the configuration data, if provided in the update.
+    pub fn setup_data_bool(&self, key: &str) -> Option<bool> {
+        self.setup_data_value(key).and_then(|v| v.as_bool())
+    }
+
+    /// Get an unsigned integer value of the configuration data, if provided in the update.
+    pub fn setup_data_u64(&self, key: &str) -> Option<u64> {
+        self.setup_data_value(key).and_then(|v| v.as_u64())
+    }
+}
+
 impl From<Integration> for IntegrationUpdate {
     fn from(intg: Integration) -> Self {
         Self {
             integration_id: Some(intg.integration_id),
             driver_id: Some(intg.driver_id),
             device_id: intg.device_id,
-            name: Some(intg.name),
+            name: Some(owned_name_map(intg.name)),
             icon: intg.icon,
             enabled: Some(intg.enabled),
             setup_data: Some(intg.setup_data),
@@ -397,6 +1443,19 @@ pub enum DeviceState {
     Error,
 }
 
+impl DeviceState {
+    /// Get the corresponding integration state.
+    pub fn to_integration_state(&self) -> IntegrationState {
+        match self {
+            DeviceState::Unknown => IntegrationState::Unknown,
+            DeviceState::Connecting => IntegrationState::Connecting,
+            DeviceState::Connected => IntegrationState::Connected,
+            DeviceState::Disconnected => IntegrationState::Disconnected,
+            DeviceState::Error => IntegrationState::Error,
+        }
+    }
+}
+
 /// Integration driver states.
 ///
 /// The intermediate states `Connected` (but not yet authenticated) and `Disconnecting` are omitted.
@@ -415,6 +1474,20 @@ pub enum DriverState {
     Error,
 }
 
+impl DriverState {
+    /// Get the corresponding integration state.
+    pub fn to_integration_state(&self) -> IntegrationState {
+        match self {
+            DriverState::NotConfigured => IntegrationState::NotConfigured,
+            DriverState::Idle => IntegrationState::Idle,
+            DriverState::Connecting => IntegrationState::Connecting,
+            DriverState::Active => IntegrationState::Active,
+            DriverState::Reconnecting => IntegrationState::Reconnecting,
+            DriverState::Error => IntegrationState::Error,
+        }
+    }
+}
+
 /// Integration states.
 ///
 /// Variants will be serialized in `SCREAMING_SNAKE_CASE`.
@@ -432,3 +1505,333 @@ pub enum IntegrationState {
     Active,
     Error,
 }
+
+impl IntegrationState {
+    /// Get the driver connection state of the integration state.
+    ///
+    /// Driver related states are mapped to the corresponding driver state. Device related states
+    /// require an active driver connection and are mapped to `DriverState::Active`.
+    pub fn effective_driver_state(&self) -> DriverState {
+        match self {
+            IntegrationState::NotConfigured => DriverState::NotConfigured,
+            IntegrationState::Idle => DriverState::Idle,
+            IntegrationState::Connecting => DriverState::Connecting,
+            IntegrationState::Reconnecting => DriverState::Reconnecting,
+            IntegrationState::Unknown
+            | IntegrationState::Connected
+            | IntegrationState::Disconnected
+            | IntegrationState::Active => DriverState::Active,
+            IntegrationState::Error => DriverState::Error,
+        }
+    }
+
+    /// Get the device state of the integration state.
+    ///
+    /// Device related states are mapped to the corresponding device state, driver related states
+    /// to the closest device state.
+    pub fn effective_device_state(&self) -> DeviceState {
+        match self {
+            IntegrationState::NotConfigured
+            | IntegrationState::Unknown
+            | IntegrationState::Idle => DeviceState::Unknown,
+            IntegrationState::Connecting | IntegrationState::Reconnecting => {
+                DeviceState::Connecting
+            }
+            IntegrationState::Connected | IntegrationState::Active => DeviceState::Connected,
+            IntegrationState::Disconnected => DeviceState::Disconnected,
+            IntegrationState::Error => DeviceState::Error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_driver(name: HashMap<String, String>) -> IntegrationDriver {
+        serde_json::from_value(serde_json::json!({
+            "driver_id": "test",
+            "name": name,
+            "driver_type": "EXTERNAL",
+            "driver_url": "ws://localhost:9090",
+            "version": "1.0.0",
+            "enabled": true,
+            "device_discovery": false,
+            "setup_data_schema": {}
+        }))
+        .expect("Invalid driver")
+    }
+
+    #[test]
+    fn name_map_conversion_round_trip() {
+        let name = shared_name_map(HashMap::from([("en".into(), "Test".into())]));
+        let shared = name.clone();
+
+        assert_eq!(Some(&"Test".to_string()), shared.get("en"));
+        assert_eq!(
+            serde_json::json!({ "en": "Test" }),
+            serde_json::to_value(&name).unwrap()
+        );
+        let map = owned_name_map(name);
+        assert_eq!(1, map.len());
+        assert_eq!(Some(&"Test".to_string()), map.get("en"));
+    }
+
+    #[test]
+    fn driver_builder_defaults() {
+        let driver = IntegrationDriver::builder()
+            .driver_id("test")
+            .name(HashMap::from([("en".into(), "Test".into())]))
+            .driver_url("ws://localhost:9090")
+            .version("1.0.0")
+            .build()
+            .expect("valid driver");
+
+        assert_eq!(DriverType::External, driver.driver_type);
+        assert!(driver.enabled);
+        assert!(!driver.device_discovery);
+        assert_eq!(None, driver.instance_count);
+        assert_eq!(
+            serde_json::json!({}),
+            serde_json::to_value(&driver.setup_data_schema).unwrap()
+        );
+    }
+
+    #[test]
+    fn driver_builder_requires_fields() {
+        let builder = IntegrationDriverBuilder::from(test_driver(HashMap::from([(
+            "en".into(),
+            "Test".into(),
+        )])));
+        assert!(builder.clone().build().is_ok());
+
+        for (builder, field) in [
+            (builder.clone().driver_id(""), "driver_id"),
+            (builder.clone().name(HashMap::new()), "name"),
+            (builder.clone().driver_url("http://localhost"), "driver_url"),
+            (builder.clone().version(""), "version"),
+        ] {
+            let error = builder.build().expect_err(field);
+            assert!(error.contains(field), "{error}");
+        }
+    }
+
+    #[test]
+    fn redacted_driver() {
+        let mut driver = test_driver(HashMap::from([("en".into(), "Test".into())]));
+        driver.token = Some("secret".into());
+
+        let driver = driver.redacted();
+        assert_eq!(None, driver.token);
+        assert_eq!(Some(true), driver.pwd_protected);
+
+        let mut driver = test_driver(HashMap::from([("en".into(), "Test".into())]));
+        driver.redact();
+        assert_eq!(None, driver.token);
+        assert_eq!(None, driver.pwd_protected);
+    }
+
+    #[test]
+    fn driver_from_update_requires_fields() {
+        let update: IntegrationDriverUpdate = serde_json::from_value(serde_json::json!({
+            "name": { "en": "Test" },
+            "driver_url": "ws://localhost:9090"
+        }))
+        .unwrap();
+
+        let errors = IntegrationDriver::try_from(update).expect_err("missing fields");
+        let errors = errors.field_errors();
+        assert!(errors.contains_key("driver_id"));
+        assert!(errors.contains_key("version"));
+        assert!(!errors.contains_key("name"));
+    }
+
+    #[test]
+    fn driver_update_apply_to() {
+        let mut driver = test_driver(HashMap::from([("en".into(), "Test".into())]));
+        let update: IntegrationDriverUpdate = serde_json::from_value(serde_json::json!({
+            "driver_id": "other",
+            "name": { "en": "Foobar" },
+            "token": "secret",
+            "device_discovery": true
+        }))
+        .unwrap();
+
+        update.apply_to(&mut driver).expect("valid update");
+        assert_eq!("test", driver.driver_id);
+        assert_eq!(Some("Foobar"), driver.name.get("en").map(String::as_str));
+        assert_eq!(Some("secret"), driver.token.as_deref());
+        assert!(driver.device_discovery);
+        // unchanged fields
+        assert_eq!("ws://localhost:9090", driver.driver_url);
+        assert_eq!("1.0.0", driver.version);
+        assert!(driver.enabled);
+    }
+
+    #[test]
+    fn driver_update_applied_requires_valid_update() {
+        let driver = test_driver(HashMap::from([("en".into(), "Test".into())]));
+        let update: IntegrationDriverUpdate = serde_json::from_value(serde_json::json!({
+            "driver_url": "http://localhost:9090",
+            "version": "2.0.0"
+        }))
+        .unwrap();
+
+        let errors = update.applied(driver).expect_err("invalid driver_url");
+        assert!(errors.field_errors().contains_key("driver_url"));
+    }
+
+    #[test]
+    fn driver_update_requires_english_name() {
+        let update = |name: serde_json::Value| -> IntegrationDriverUpdate {
+            serde_json::from_value(serde_json::json!({ "name": name, "description": name }))
+                .expect("Invalid json")
+        };
+
+        assert!(update(serde_json::json!({ "en": "Test" }))
+            .validate()
+            .is_ok());
+        assert!(update(serde_json::json!({ "en_US": "Test" }))
+            .validate()
+            .is_ok());
+        let errors = update(serde_json::json!({ "de": "Test" }))
+            .validate()
+            .expect_err("missing en");
+        assert!(errors.field_errors().contains_key("name"));
+        assert!(errors.field_errors().contains_key("description"));
+    }
+
+    #[test]
+    fn driver_update_requires_semver_versions() {
+        let update = |version: &str| -> IntegrationDriverUpdate {
+            serde_json::from_value(
+                serde_json::json!({ "version": version, "min_core_api": version }),
+            )
+            .expect("Invalid json")
+        };
+
+        for version in [
+            "1.0.0",
+            "1.0.0-beta.1",
+            "1.0.0+build.1",
+            "0.20.1-rc1+20240102",
+        ] {
+            assert!(update(version).validate().is_ok(), "{version}");
+        }
+        for version in ["1.0", "v1.0.0", "1.0.0-", "1.0.0 beta", ""] {
+            let errors = update(version).validate().expect_err(version);
+            assert!(errors.field_errors().contains_key("version"), "{version}");
+            assert!(
+                errors.field_errors().contains_key("min_core_api"),
+                "{version}"
+            );
+        }
+    }
+
+    #[test]
+    fn driver_update_requires_websocket_url() {
+        let update = |url: &str| -> IntegrationDriverUpdate {
+            serde_json::from_value(serde_json::json!({ "driver_url": url })).expect("Invalid json")
+        };
+
+        for url in [
+            "ws://localhost:9090",
+            "wss://192.168.1.2:9090/ws",
+            "WS://driver.local",
+            "ws://[::1]:9090/ws",
+            "ws://[fe80::1]",
+        ] {
+            assert!(update(url).validate().is_ok(), "{url}");
+        }
+        for url in [
+            "http://localhost:9090",
+            "ws://",
+            "ws:// localhost",
+            "localhost:9090",
+            "ws://:9090",
+            "ws://localhost:",
+            "ws://localhost:port",
+            "ws://[::1",
+        ] {
+            let errors = update(url).validate().expect_err(url);
+            assert!(errors.field_errors().contains_key("driver_url"), "{url}");
+        }
+    }
+
+    #[test]
+    fn display_integration_driver() {
+        let driver = test_driver(HashMap::from([
+            ("de".into(), "Testtreiber".into()),
+            ("en".into(), "Test driver".into()),
+        ]));
+        assert_eq!("Test driver (test) v1.0.0 [EXTERNAL]", driver.to_string());
+    }
+
+    #[test]
+    fn display_integration_driver_with_empty_name() {
+        let driver = test_driver(HashMap::new());
+        assert_eq!("test (test) v1.0.0 [EXTERNAL]", driver.to_string());
+    }
+
+    #[test]
+    fn version_at_least_compares_numeric_parts() {
+        assert_eq!(Some(true), version_at_least("1.2.3", "1.2.3"));
+        assert_eq!(Some(true), version_at_least("1.10.0", "1.9.0"));
+        assert_eq!(Some(true), version_at_least("2.0", "1.9.9"));
+        assert_eq!(Some(false), version_at_least("1.2", "1.2.1"));
+        assert_eq!(Some(false), version_at_least("0.9.0", "0.10.0"));
+    }
+
+    #[test]
+    fn version_at_least_ignores_build_metadata() {
+        assert_eq!(Some(true), version_at_least("1.2.3+build.1", "1.2.3"));
+        assert_eq!(Some(true), version_at_least("1.2.3", "1.2.3+build.2"));
+    }
+
+    #[test]
+    fn version_at_least_ranks_pre_release_below_release() {
+        assert_eq!(Some(false), version_at_least("1.0.0-beta", "1.0.0"));
+        assert_eq!(Some(true), version_at_least("1.0.0", "1.0.0-beta"));
+        assert_eq!(Some(true), version_at_least("1.0.1-beta", "1.0.0"));
+        assert_eq!(
+            Some(true),
+            version_at_least("1.0.0-beta.11", "1.0.0-beta.2")
+        );
+        assert_eq!(
+            Some(false),
+            version_at_least("1.0.0-alpha", "1.0.0-alpha.1")
+        );
+    }
+
+    #[test]
+    fn version_at_least_rejects_invalid_versions() {
+        assert_eq!(None, version_at_least("9", "10.x"));
+        assert_eq!(None, version_at_least("latest", "1.0.0"));
+        assert_eq!(None, version_at_least("1.0.0", ""));
+        assert_eq!(None, version_at_least("1.0.0-", "1.0.0"));
+    }
+
+    // the simplified comparison is used without the `semver` feature: test it independently
+    #[test]
+    fn simple_version_at_least_matches_semver_precedence() {
+        assert_eq!(Some(true), simple_version_at_least("1.2.3", "1.2.3"));
+        assert_eq!(Some(true), simple_version_at_least("0.10.0", "0.9.0"));
+        assert_eq!(Some(false), simple_version_at_least("9", "10"));
+        assert_eq!(
+            Some(true),
+            simple_version_at_least("1.2.3+build.1", "1.2.3")
+        );
+        assert_eq!(Some(false), simple_version_at_least("1.0.0-beta", "1.0.0"));
+        assert_eq!(Some(true), simple_version_at_least("1.0.0", "1.0.0-rc.1"));
+        assert_eq!(
+            Some(true),
+            simple_version_at_least("1.0.0-rc.1", "1.0.0-beta.2")
+        );
+        assert_eq!(
+            Some(false),
+            simple_version_at_least("1.0.0-1", "1.0.0-alpha")
+        );
+        assert_eq!(None, simple_version_at_least("9", "ten"));
+        assert_eq!(None, simple_version_at_least("v1.0.0", "1.0.0"));
+    }
+}
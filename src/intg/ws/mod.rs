@@ -70,7 +70,7 @@ pub enum R2Response {
 /// Integration specific events emitted from Remote Two
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
-#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[derive(AsRefStr, Display, EnumString, IntoStaticStr, VariantNames)] // strum_macros
 #[strum(serialize_all = "snake_case")]
 pub enum R2Event {
     Connect,
@@ -80,6 +80,13 @@ pub enum R2Event {
     AbortDriverSetup,
 }
 
+impl R2Event {
+    /// Get the message name of the event as used in the WebSocket `msg` field.
+    pub fn as_msg_str(&self) -> &'static str {
+        (*self).into()
+    }
+}
+
 /// Integration driver response messages.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -96,7 +103,7 @@ pub enum DriverResponse {
 /// Events emitted from the integration driver
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
-#[derive(AsRefStr, Display, EnumString, VariantNames)] // strum_macros
+#[derive(AsRefStr, Display, EnumString, IntoStaticStr, VariantNames)] // strum_macros
 #[strum(serialize_all = "snake_case")]
 pub enum DriverEvent {
     AuthRequired,
@@ -107,6 +114,22 @@ pub enum DriverEvent {
     DriverSetupChange,
 }
 
+impl DriverEvent {
+    /// Get the message name of the event as used in the WebSocket `msg` field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uc_api::intg::ws::DriverEvent;
+    /// assert_eq!("entity_change", DriverEvent::EntityChange.as_msg_str());
+    /// let msg: &'static str = DriverEvent::DriverSetupChange.into();
+    /// assert_eq!("driver_setup_change", msg);
+    /// ```
+    pub fn as_msg_str(&self) -> &'static str {
+        (*self).into()
+    }
+}
+
 /// Request messages initiated from the Remote to the integration driver.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -172,6 +195,60 @@ pub struct AvailableEntitiesFilter {
     pub entity_type: Option<EntityType>,
 }
 
+impl AvailableEntitiesFilter {
+    /// Filter matching all entities.
+    pub fn all() -> Self {
+        Self {
+            device_id: None,
+            entity_type: None,
+        }
+    }
+
+    /// Filter matching all entities of the given entity type.
+    pub fn for_type(entity_type: EntityType) -> Self {
+        Self {
+            device_id: None,
+            entity_type: Some(entity_type),
+        }
+    }
+
+    /// Check if the given entity matches the filter.
+    ///
+    /// An entity matches if both the device identifier and the entity type match. Filter options
+    /// which are not set match any entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use uc_api::EntityType;
+    /// use uc_api::intg::AvailableIntgEntity;
+    /// use uc_api::intg::ws::AvailableEntitiesFilter;
+    /// let entity = AvailableIntgEntity {
+    ///     entity_id: "light1".into(),
+    ///     device_id: None,
+    ///     entity_type: EntityType::Light,
+    ///     device_class: None,
+    ///     name: HashMap::from([("en".into(), "Light".into())]),
+    ///     features: None,
+    ///     area: None,
+    ///     area_path: None,
+    ///     options: None,
+    ///     attributes: None,
+    /// };
+    /// assert!(AvailableEntitiesFilter::all().matches(&entity));
+    /// assert!(AvailableEntitiesFilter::for_type(EntityType::Light).matches(&entity));
+    /// assert!(!AvailableEntitiesFilter::for_type(EntityType::Switch).matches(&entity));
+    /// ```
+    pub fn matches(&self, entity: &AvailableIntgEntity) -> bool {
+        let device_match = self.device_id.is_none() || self.device_id == entity.device_id;
+        let type_match = self
+            .entity_type
+            .map_or(true, |entity_type| entity_type == entity.entity_type);
+        device_match && type_match
+    }
+}
+
 /// Payload data of `available_entities` response message in `msg_data` property.
 #[skip_serializing_none]
 #[derive(Debug, Clone, Deserialize, Serialize, Validate)]
@@ -43,15 +43,28 @@ use regex::Regex;
 
 pub mod core;
 mod entity;
+mod entity_params;
+mod id;
 pub mod intg;
 pub mod model;
 pub mod util;
 pub mod ws;
 
 pub use entity::*;
+pub use entity_params::*;
+pub use id::*;
 
 lazy_static! {
     // max length is a dedicated validation for better error messages
     static ref REGEX_ID_CHARS: Regex = Regex::new(r"^[a-zA-Z0-9-_]{1,}$").unwrap();
     static ref REGEX_ICON_ID: Regex = Regex::new(r"^[a-zA-Z0-9-_\\.:]{1,}$").unwrap();
+    // WebSocket URL: only ws:// and wss:// schemes with a host name, IPv4 or IPv6 address and an
+    // optional port are allowed
+    static ref REGEX_DRIVER_URL: Regex = Regex::new(
+        r"(?i)^wss?://([^\s/?#:\[\]]+|\[[0-9a-f:.]+\])(:\d{1,5})?([/?#]\S*)?$"
+    ).unwrap();
+    // lenient SemVer: major.minor.patch with optional pre-release and build metadata
+    static ref REGEX_SEMVER: Regex = Regex::new(
+        r"^\d+\.\d+\.\d+(-[0-9A-Za-z-]+(\.[0-9A-Za-z-]+)*)?(\+[0-9A-Za-z-]+(\.[0-9A-Za-z-]+)*)?$"
+    ).unwrap();
 }
@@ -22,6 +22,36 @@ pub enum SetupChangeEventType {
     Stop,
 }
 
+impl SetupChangeEventType {
+    /// Get the allowed event types following this event type in a setup flow.
+    ///
+    /// A setup flow starts with `Start`, followed by any number of `Setup` events and is finished
+    /// with `Stop`.
+    pub fn valid_next_events(&self) -> &'static [SetupChangeEventType] {
+        match self {
+            SetupChangeEventType::Start | SetupChangeEventType::Setup => {
+                &[SetupChangeEventType::Setup, SetupChangeEventType::Stop]
+            }
+            SetupChangeEventType::Stop => &[],
+        }
+    }
+
+    /// Check if the given event type may follow this event type in a setup flow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uc_api::model::intg::SetupChangeEventType;
+    /// assert!(SetupChangeEventType::Start.transitions_to(SetupChangeEventType::Setup));
+    /// assert!(SetupChangeEventType::Setup.transitions_to(SetupChangeEventType::Stop));
+    /// assert!(!SetupChangeEventType::Setup.transitions_to(SetupChangeEventType::Start));
+    /// assert!(!SetupChangeEventType::Stop.transitions_to(SetupChangeEventType::Setup));
+    /// ```
+    pub fn transitions_to(&self, next: SetupChangeEventType) -> bool {
+        self.valid_next_events().contains(&next)
+    }
+}
+
 #[derive(
     Debug, Clone, Copy, AsRefStr, Display, EnumString, PartialEq, Eq, Deserialize, Serialize,
 )]
@@ -48,14 +78,67 @@ pub enum IntegrationSetupState {
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum IntegrationSetupError {
+    /// No error. Not retryable.
     None,
+    /// Device or service not found. Permanent error, not retryable.
     NotFound,
+    /// Connection to the device or service refused. Transient error, retryable.
     ConnectionRefused,
+    /// Authorization failed. Permanent error, not retryable: new credentials are required.
     AuthorizationError,
+    /// Device or service did not respond in time. Transient error, retryable.
     Timeout,
+    /// Other error. Treated as transient error, retryable.
     Other,
 }
 
+impl IntegrationSetupError {
+    /// Map an HTTP status code of a failed device request to a setup error.
+    ///
+    /// Intended for REST based integrations to translate HTTP errors during device setup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uc_api::model::intg::IntegrationSetupError;
+    /// assert_eq!(IntegrationSetupError::AuthorizationError, IntegrationSetupError::from_http_status(403));
+    /// assert_eq!(IntegrationSetupError::Timeout, IntegrationSetupError::from_http_status(504));
+    /// assert_eq!(IntegrationSetupError::Other, IntegrationSetupError::from_http_status(500));
+    /// ```
+    pub fn from_http_status(status: u16) -> Self {
+        match status {
+            401 | 403 => Self::AuthorizationError,
+            404 => Self::NotFound,
+            408 | 504 => Self::Timeout,
+            503 => Self::ConnectionRefused,
+            _ => Self::Other,
+        }
+    }
+
+    /// Get the corresponding HTTP status code of the setup error.
+    ///
+    /// `None` is mapped to `200`, all other errors to the primary status code used in
+    /// [`from_http_status`](Self::from_http_status), or `500` for `Other`.
+    pub fn to_http_status(&self) -> u16 {
+        match self {
+            Self::None => 200,
+            Self::NotFound => 404,
+            Self::ConnectionRefused => 503,
+            Self::AuthorizationError => 401,
+            Self::Timeout => 408,
+            Self::Other => 500,
+        }
+    }
+
+    /// Check if the setup error is transient and the setup flow may be retried.
+    ///
+    /// Returns `true` for `Timeout`, `ConnectionRefused` and `Other`, and `false` for the permanent
+    /// `AuthorizationError` and `NotFound` errors, and for `None`.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Timeout | Self::ConnectionRefused | Self::Other)
+    }
+}
+
 /// If set, the setup process waits for the specified user action.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -2,8 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
-use std::collections::HashMap;
-use validator::Validate;
+use std::collections::{HashMap, HashSet};
+use validator::{Validate, ValidationError};
 
 /// Confirmation screen, e.g. to agree with something when setting up an integration driver.
 #[skip_serializing_none]
@@ -25,14 +25,44 @@ pub struct ConfirmationPage {
 
 /// Settings definition page, e.g. to configure an integration driver.
 #[derive(Clone, Debug, Serialize, Deserialize, Validate)]
+#[validate(schema(function = "validate_unique_setting_ids"))]
 pub struct SettingsPage {
     /// Language specific settings page title.
     pub title: HashMap<String, String>,
     /// One or multiple input field definitions, with optional pre-set values.
+    /// The setting identifiers must be unique.
     #[validate]
     pub settings: Vec<Setting>,
 }
 
+impl SettingsPage {
+    /// Get the setting with the given identifier.
+    pub fn get_setting(&self, id: &str) -> Option<&Setting> {
+        self.settings.iter().find(|s| s.id == id)
+    }
+
+    /// Get a mutable reference to the setting with the given identifier, e.g. to pre-set a value.
+    pub fn get_setting_mut(&mut self, id: &str) -> Option<&mut Setting> {
+        self.settings.iter_mut().find(|s| s.id == id)
+    }
+
+    /// Check if the page contains a setting with the given identifier.
+    pub fn has_setting(&self, id: &str) -> bool {
+        self.get_setting(id).is_some()
+    }
+}
+
+/// Setting identifiers must be unique within a settings page.
+fn validate_unique_setting_ids(page: &SettingsPage) -> Result<(), ValidationError> {
+    let mut ids = HashSet::new();
+    if let Some(setting) = page.settings.iter().find(|s| !ids.insert(&s.id)) {
+        let mut error = ValidationError::new("DUPLICATE_ID");
+        error.message = Some(format!("Duplicate setting id: {}", setting.id).into());
+        return Err(error);
+    }
+    Ok(())
+}
+
 /// An input setting is of a specific type defined in `field.type` which defines how it is presented to the user.
 ///
 /// Inspired by the [Homey SDK settings](https://apps.developer.homey.app/the-basics/devices/settings) concept.
@@ -179,3 +209,51 @@ pub struct Label {
     /// Static text to display next to the label
     pub value: HashMap<String, String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn settings_page(ids: &[&str]) -> SettingsPage {
+        let settings: Vec<_> = ids
+            .iter()
+            .map(|id| json!({ "id": id, "label": { "en": id }, "field": { "text": {} } }))
+            .collect();
+        serde_json::from_value(json!({ "title": { "en": "Settings" }, "settings": settings }))
+            .expect("Invalid settings page")
+    }
+
+    #[test]
+    fn get_setting() {
+        let mut page = settings_page(&["host", "port"]);
+
+        assert!(page.has_setting("port"));
+        assert_eq!(
+            Some("host"),
+            page.get_setting("host").map(|s| s.id.as_str())
+        );
+        assert!(page.get_setting("foobar").is_none());
+        assert!(!page.has_setting("foobar"));
+
+        let setting = page.get_setting_mut("port").expect("missing setting");
+        setting.field = Field::Text(Text {
+            value: Some("8080".into()),
+            regex: None,
+        });
+        assert!(matches!(
+            &page.get_setting("port").unwrap().field,
+            Field::Text(Text { value: Some(v), .. }) if v == "8080"
+        ));
+    }
+
+    #[test]
+    fn validate_duplicate_setting_ids() {
+        assert!(settings_page(&["host", "port"]).validate().is_ok());
+
+        let errors = settings_page(&["host", "port", "host"])
+            .validate()
+            .expect_err("duplicate id");
+        assert!(errors.to_string().contains("Duplicate setting id: host"));
+    }
+}
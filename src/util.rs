@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::collections::HashMap;
+use uuid::Uuid;
 
 /// Retrieve a language text from a language map.
 ///
@@ -78,6 +79,174 @@ pub fn text_from_language_map(
     }
 }
 
+/// Validate that a language text map contains an English fallback text.
+///
+/// Any English variant is accepted, e.g. `en`, `en_US` or `en-UK`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use uc_api::util::validate_language_map;
+///
+/// let map = HashMap::from([("en_US".into(), "Living room".into())]);
+/// assert!(validate_language_map(&map).is_ok());
+/// let map = HashMap::from([("de".into(), "Wohnzimmer".into())]);
+/// assert!(validate_language_map(&map).is_err());
+/// ```
+pub fn validate_language_map(map: &HashMap<String, String>) -> Result<(), &'static str> {
+    validate_english_fallback(map)
+}
+
+/// Check for an English language key: `en` or `en` with a country suffix like `en_US` or `en-US`.
+fn validate_english_fallback(map: &HashMap<String, String>) -> Result<(), &'static str> {
+    let is_english = |key: &String| {
+        key.strip_prefix("en")
+            .is_some_and(|suffix| suffix.is_empty() || suffix.starts_with(['_', '-']))
+    };
+    if map.keys().any(is_english) {
+        Ok(())
+    } else {
+        Err("Missing required 'en' language entry")
+    }
+}
+
+/// Check if the version string is a SemVer version.
+///
+/// The check is lenient: the `major.minor.patch` version numbers are required, pre-release and
+/// build metadata are optional. Leading zeros are not rejected.
+///
+/// # Examples
+///
+/// ```
+/// use uc_api::util::is_semver;
+/// assert!(is_semver("1.0.0"));
+/// assert!(is_semver("1.0.0-beta.1+build.2"));
+/// assert!(!is_semver("1.0"));
+/// assert!(!is_semver("v1.0.0"));
+/// ```
+pub fn is_semver(v: &str) -> bool {
+    crate::REGEX_SEMVER.is_match(v)
+}
+
+/// Generate a random identifier, e.g. for a new integration driver.
+///
+/// The identifier is a hyphenated UUID v4 string, which is also a valid entity and driver
+/// identifier.
+///
+/// # Examples
+///
+/// ```
+/// use uc_api::util::{generate_id, is_auto_id};
+/// let id = generate_id();
+/// assert_eq!(36, id.len());
+/// assert!(is_auto_id(&id));
+/// ```
+pub fn generate_id() -> String {
+    Uuid::new_v4().hyphenated().to_string()
+}
+
+/// Check if the identifier is a generated identifier in the format of [`generate_id`].
+///
+/// # Examples
+///
+/// ```
+/// use uc_api::util::is_auto_id;
+/// assert!(is_auto_id("c8f3b6c5-1d0e-4b5a-9f2e-3a4b5c6d7e8f"));
+/// assert!(!is_auto_id("my_driver"));
+/// // UUID v1
+/// assert!(!is_auto_id("c8f3b6c5-1d0e-1b5a-9f2e-3a4b5c6d7e8f"));
+/// ```
+pub fn is_auto_id(id: &str) -> bool {
+    id.len() == 36
+        && Uuid::try_parse(id).is_ok_and(|uuid| uuid.get_version() == Some(uuid::Version::Random))
+}
+
+/// Language text map with ISO 639-1 language code keys and an optional country suffix.
+///
+/// Used for the names and descriptions of drivers and integrations, see
+/// [`NameMap`](crate::intg::NameMap). An English text with key `en` should always be provided as
+/// fallback. See [`LanguageMapExt`] for helper methods.
+pub type LanguageMap = HashMap<String, String>;
+
+/// Helper methods of a [`LanguageMap`].
+///
+/// # Examples
+///
+/// ```
+/// use uc_api::util::{LanguageMap, LanguageMapExt};
+///
+/// let map = LanguageMap::builder()
+///     .en("Living room")
+///     .text("de", "Wohnzimmer")
+///     .build();
+/// assert_eq!(Some("Wohnzimmer"), map.get_for_lang("de_CH"));
+/// assert_eq!(Some("Living room"), map.get_for_lang("it"));
+/// assert!(map.validate_has_fallback().is_ok());
+/// assert_eq!(
+///     serde_json::json!({ "en": "Living room", "de": "Wohnzimmer" }),
+///     serde_json::to_value(&map).unwrap()
+/// );
+/// ```
+pub trait LanguageMapExt {
+    /// Create a builder for a language map.
+    fn builder() -> LanguageMapBuilder;
+
+    /// Set the English fallback text.
+    fn insert_en(&mut self, text: impl Into<String>);
+
+    /// Retrieve the text for the given language, see [`text_from_language_map`] for the fallback
+    /// rules.
+    fn get_for_lang(&self, lang: &str) -> Option<&str>;
+
+    /// Check if the map contains an English fallback text, see [`validate_language_map`].
+    fn validate_has_fallback(&self) -> Result<(), &'static str>;
+}
+
+impl LanguageMapExt for LanguageMap {
+    fn builder() -> LanguageMapBuilder {
+        LanguageMapBuilder::default()
+    }
+
+    fn insert_en(&mut self, text: impl Into<String>) {
+        self.insert("en".into(), text.into());
+    }
+
+    fn get_for_lang(&self, lang: &str) -> Option<&str> {
+        text_from_language_map(Some(self), lang)
+    }
+
+    fn validate_has_fallback(&self) -> Result<(), &'static str> {
+        validate_english_fallback(self)
+    }
+}
+
+/// Builder for a [`LanguageMap`].
+#[derive(Debug, Clone, Default)]
+pub struct LanguageMapBuilder {
+    map: LanguageMap,
+}
+
+impl LanguageMapBuilder {
+    /// Set the English fallback text.
+    pub fn en(mut self, text: impl Into<String>) -> Self {
+        self.map.insert_en(text);
+        self
+    }
+
+    /// Set the text of the given language.
+    pub fn text(mut self, lang: impl Into<String>, text: impl Into<String>) -> Self {
+        self.map.insert(lang.into(), text.into());
+        self
+    }
+
+    /// Create the language map. The map is not validated, see
+    /// [`validate_has_fallback`](LanguageMapExt::validate_has_fallback).
+    pub fn build(self) -> LanguageMap {
+        self.map
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,4 +298,49 @@ mod tests {
         let text = text_from_language_map(map.as_ref(), "it");
         assert_eq!(Some("English fallback"), text);
     }
+
+    #[test]
+    fn language_map_serde_is_transparent() {
+        let json = serde_json::json!({ "en": "English", "de_CH": "Swiss German" });
+        let map: LanguageMap = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(2, map.len());
+        assert_eq!(Some("Swiss German"), map.get_for_lang("de_DE"));
+        assert_eq!(json, serde_json::to_value(map).unwrap());
+    }
+
+    #[test]
+    fn language_map_requires_english_fallback() {
+        let mut map = LanguageMap::from([("de".into(), "Deutsch".into())]);
+        assert!(map.validate_has_fallback().is_err());
+
+        map.insert_en("English");
+        assert!(map.validate_has_fallback().is_ok());
+    }
+
+    #[test]
+    fn validate_language_map_requires_english() {
+        for (map, valid) in [
+            (HashMap::from([("de".into(), "Deutsch".into())]), false),
+            (HashMap::new(), false),
+            (HashMap::from([("en".into(), "English".into())]), true),
+            (HashMap::from([("en_US".into(), "US English".into())]), true),
+            (HashMap::from([("en-UK".into(), "UK English".into())]), true),
+            (HashMap::from([("eng".into(), "English".into())]), false),
+        ] {
+            assert_eq!(valid, validate_language_map(&map).is_ok(), "{map:?}");
+            assert_eq!(valid, map.validate_has_fallback().is_ok(), "{map:?}");
+        }
+    }
+
+    #[test]
+    fn generated_id_is_valid_id() {
+        for _ in 0..10 {
+            let id = generate_id();
+            assert!(crate::REGEX_ID_CHARS.is_match(&id), "{id}");
+            assert!(crate::DriverId::new(id.clone()).is_ok(), "{id}");
+            assert!(is_auto_id(&id), "{id}");
+        }
+        assert!(!is_auto_id(&generate_id().replace('-', "")));
+    }
 }
@@ -4,12 +4,16 @@
 //! Common WebSocket messages used for Core & Integration APIs.
 
 use std::collections::HashMap;
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use serde_with::skip_serializing_none;
 use strum_macros::*;
+use validator::ValidationErrors;
 
 /// WebSocket authentication type.
 #[derive(
@@ -59,7 +63,8 @@ pub enum WsAuthentication {
 /// assert!(request.extra.contains_key("bar"));
 /// ```
 #[skip_serializing_none]
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[cfg_attr(not(feature = "redact-debug"), derive(Debug))]
+#[derive(Clone, Default, Deserialize, Serialize)]
 pub struct WsMessage {
     /// Message identifier: `req`, `resp`, `event`
     pub kind: Option<String>,
@@ -304,6 +309,360 @@ impl WsMessage {
             ..Default::default()
         }
     }
+
+    /// Helper method to create a response message for the given request message.
+    ///
+    /// The `req_id` of the response is set from the request `id`. If the request doesn't have an
+    /// `id`, a `500` error response without `req_id` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use uc_api::ws::WsMessage;
+    /// let request = WsMessage::simple_request(123, "get_version");
+    /// let response = WsMessage::respond_to(&request, 200, "version", Some(json!({ "api": "0.9" })));
+    /// assert_eq!(Some("resp"), response.kind.as_deref());
+    /// assert_eq!(Some(123), response.req_id);
+    /// assert_eq!(Some(200), response.code);
+    /// assert_eq!(Some("version"), response.msg.as_deref());
+    /// ```
+    pub fn respond_to(
+        request: &WsMessage,
+        code: u16,
+        msg: impl Into<String>,
+        msg_data: Option<Value>,
+    ) -> Self {
+        match request.id {
+            Some(id) => Self {
+                kind: Some("resp".into()),
+                req_id: Some(id),
+                msg: Some(msg.into()),
+                code: Some(code),
+                msg_data,
+                ..Default::default()
+            },
+            None => Self::missing_request_id(),
+        }
+    }
+
+    /// Helper method to create an error response message for the given request message.
+    ///
+    /// The `req_id` of the response is set from the request `id`. If the request doesn't have an
+    /// `id`, a `500` error response without `req_id` is returned.
+    pub fn error_for(request: &WsMessage, code: u16, error_msg_data: WsResultMsgData) -> Self {
+        match request.id {
+            Some(id) => Self::error(id, code, error_msg_data),
+            None => Self::missing_request_id(),
+        }
+    }
+
+    fn missing_request_id() -> Self {
+        Self {
+            kind: Some("resp".into()),
+            msg: Some("result".into()),
+            code: Some(500),
+            msg_data: Some(json!({ "code": "INTERNAL_ERROR", "message": "Missing request id"})),
+            ..Default::default()
+        }
+    }
+
+    /// Get the event category of an event message.
+    ///
+    /// Returns the default `Device` category if the message doesn't contain a category.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use uc_api::ws::{EventCategory, WsMessage};
+    /// let event = WsMessage::event("foo", None, json!({}));
+    /// assert_eq!(EventCategory::Device, event.category());
+    /// let event = WsMessage::event("foo", EventCategory::Entity, json!({}));
+    /// assert_eq!(EventCategory::Entity, event.category());
+    /// ```
+    pub fn category(&self) -> EventCategory {
+        self.cat.unwrap_or_default()
+    }
+
+    /// Get the typed message kind.
+    ///
+    /// Returns `None` if the message doesn't have a `kind` field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uc_api::ws::{WsMessage, WsMessageKind};
+    /// let request = WsMessage::simple_request(123, "get_version");
+    /// assert_eq!(Some(WsMessageKind::Request), request.kind_enum());
+    /// assert_eq!(None, WsMessage::default().kind_enum());
+    /// ```
+    pub fn kind_enum(&self) -> Option<WsMessageKind> {
+        self.kind
+            .as_deref()
+            .map(|kind| kind.parse().unwrap_or_else(|e| match e {}))
+    }
+
+    /// Get the identifier to correlate the message with a request.
+    ///
+    /// Returns the `req_id` of a response message, or the `id` of a request message.
+    pub fn correlation_id(&self) -> Option<u32> {
+        self.req_id.or(self.id)
+    }
+
+    /// Check if the message has a 2xx success response code.
+    ///
+    /// Returns false if the message doesn't have a response code.
+    pub fn is_success(&self) -> bool {
+        self.code.is_some_and(|code| (200..300).contains(&code))
+    }
+
+    /// Check if the message has a 4xx client error response code.
+    pub fn is_client_error(&self) -> bool {
+        self.code.is_some_and(|code| (400..500).contains(&code))
+    }
+
+    /// Check if the message has a 5xx server error response code.
+    pub fn is_server_error(&self) -> bool {
+        self.code.is_some_and(|code| (500..600).contains(&code))
+    }
+
+    /// Set the message timestamp to the current time.
+    pub fn set_ts_now(&mut self) {
+        self.ts = Some(Utc::now());
+    }
+
+    /// Remove the message timestamp.
+    pub fn clear_ts(&mut self) {
+        self.ts = None;
+    }
+
+    /// Builder style version of [`set_ts_now`](Self::set_ts_now).
+    pub fn with_ts_now(mut self) -> Self {
+        self.set_ts_now();
+        self
+    }
+
+    /// Builder style version of [`clear_ts`](Self::clear_ts).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use uc_api::ws::{EventCategory, WsMessage};
+    /// let event = WsMessage::event("test_event", EventCategory::Device, json!({})).without_ts();
+    /// assert_eq!(None, event.ts);
+    /// ```
+    pub fn without_ts(mut self) -> Self {
+        self.clear_ts();
+        self
+    }
+
+    /// Get a non-standard field from the message root.
+    pub fn get_extra_field(&self, key: &str) -> Option<&Value> {
+        self.extra.get(key)
+    }
+
+    /// Remove and return a non-standard field from the message root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use uc_api::ws::WsMessage;
+    /// let mut msg: WsMessage =
+    ///     serde_json::from_value(json!({ "kind": "req", "id": 1, "msg": "foo", "x_trace": "abc" }))
+    ///         .unwrap();
+    /// assert!(msg.has_extra_fields());
+    /// assert_eq!(Some(json!("abc")), msg.take_extra_field("x_trace"));
+    /// assert!(!msg.has_extra_fields());
+    /// ```
+    pub fn take_extra_field(&mut self, key: &str) -> Option<Value> {
+        self.extra.remove(key)
+    }
+
+    /// Check if the message contains any non-standard fields.
+    pub fn has_extra_fields(&self) -> bool {
+        !self.extra.is_empty()
+    }
+
+    /// Convert the message into a typed request message.
+    ///
+    /// Non-standard fields are dropped.
+    ///
+    /// # Errors
+    ///
+    /// The original message is returned if it's not a `req` message or the `id` or `msg` fields
+    /// are missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uc_api::ws::WsMessage;
+    /// let request = WsMessage::simple_request(123, "get_version").try_into_request().unwrap();
+    /// assert_eq!(123, request.id);
+    /// assert_eq!("get_version", request.msg);
+    ///
+    /// let response = WsMessage::response_json(123, "version", serde_json::json!({}));
+    /// assert!(response.try_into_request().is_err());
+    /// ```
+    #[allow(clippy::result_large_err)] // the unchanged message is handed back to the caller
+    pub fn try_into_request(self) -> Result<WsRequest, WsMessage> {
+        match (self.kind.as_deref(), self.id, &self.msg) {
+            (Some("req"), Some(id), Some(_)) => Ok(WsRequest {
+                kind: "req".into(),
+                id,
+                msg: self.msg.unwrap_or_default(),
+                msg_data: self.msg_data,
+            }),
+            _ => Err(self),
+        }
+    }
+
+    /// Convert the message into a typed response message.
+    ///
+    /// Non-standard fields are dropped.
+    ///
+    /// # Errors
+    ///
+    /// The original message is returned if it's not a `resp` message or the `req_id`, `msg` or
+    /// `code` fields are missing.
+    #[allow(clippy::result_large_err)] // the unchanged message is handed back to the caller
+    pub fn try_into_response(self) -> Result<WsResponse, WsMessage> {
+        match (self.kind.as_deref(), self.req_id, &self.msg, self.code) {
+            (Some("resp"), Some(req_id), Some(_), Some(code)) => Ok(WsResponse {
+                kind: "resp".into(),
+                req_id,
+                msg: self.msg.unwrap_or_default(),
+                code,
+                msg_data: self.msg_data,
+            }),
+            _ => Err(self),
+        }
+    }
+
+    /// Convert the message into a typed event message.
+    ///
+    /// See [`WsEvent::try_from`] for the conversion rules.
+    ///
+    /// # Errors
+    ///
+    /// The original message is returned if it's not an `event` message or the `msg` field is
+    /// missing.
+    #[allow(clippy::result_large_err)] // the unchanged message is handed back to the caller
+    pub fn try_into_event(self) -> Result<WsEvent, WsMessage> {
+        WsEvent::try_from(self)
+    }
+}
+
+/// Short message summary for logging purposes, without the message payload.
+///
+/// Only the set fields are included: `{kind} #{id|req_id} {code} {msg} [{cat}]`. E.g.
+/// `event {msg} [{cat}]`, `req #{id} {msg}` or `resp #{req_id} {code} {msg}`.
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use uc_api::ws::{EventCategory, WsMessage};
+/// let event = WsMessage::event("test_event", EventCategory::Device, json!({ "foo": "bar" }));
+/// assert_eq!("event test_event [DEVICE]", event.to_string());
+/// let request = WsMessage::simple_request(123, "get_version");
+/// assert_eq!("req #123 get_version", request.to_string());
+/// let response = WsMessage::response_json(123, "version", json!({ "api": "0.12.1" }));
+/// assert_eq!("resp #123 200 version", response.to_string());
+/// ```
+impl Display for WsMessage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.kind.as_deref().unwrap_or("unknown"))?;
+        if let Some(id) = self.id {
+            write!(f, " #{id}")?;
+        }
+        if let Some(req_id) = self.req_id {
+            write!(f, " #{req_id}")?;
+        }
+        if let Some(code) = self.code {
+            write!(f, " {code}")?;
+        }
+        if let Some(msg) = &self.msg {
+            write!(f, " {msg}")?;
+        }
+        if let Some(cat) = self.cat {
+            write!(f, " [{cat}]")?;
+        }
+        Ok(())
+    }
+}
+
+/// Message kind of a [`WsMessage`].
+///
+/// Parsing from a string with `FromStr` never fails: unknown kinds are returned as `Unknown`.
+/// The `TryFrom<&str>` conversion only accepts the known message kinds.
+///
+/// # Examples
+///
+/// ```
+/// use uc_api::ws::WsMessageKind;
+/// assert_eq!(Ok(WsMessageKind::Response), "resp".parse());
+/// assert_eq!(Ok(WsMessageKind::Unknown("foo".into())), "foo".parse());
+/// assert_eq!(Ok(WsMessageKind::Event), WsMessageKind::try_from("event"));
+/// assert!(WsMessageKind::try_from("foo").is_err());
+/// assert_eq!("req", WsMessageKind::Request.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WsMessageKind {
+    /// Request message: `req`
+    Request,
+    /// Response message: `resp`
+    Response,
+    /// Event message: `event`
+    Event,
+    /// Any other, non-standard message kind.
+    Unknown(String),
+}
+
+impl WsMessageKind {
+    /// Get the message kind as used in the `kind` field of a message.
+    pub fn as_str(&self) -> &str {
+        match self {
+            WsMessageKind::Request => "req",
+            WsMessageKind::Response => "resp",
+            WsMessageKind::Event => "event",
+            WsMessageKind::Unknown(kind) => kind,
+        }
+    }
+}
+
+impl Display for WsMessageKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for WsMessageKind {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "req" => WsMessageKind::Request,
+            "resp" => WsMessageKind::Response,
+            "event" => WsMessageKind::Event,
+            _ => WsMessageKind::Unknown(s.into()),
+        })
+    }
+}
+
+impl TryFrom<&str> for WsMessageKind {
+    /// The unknown message kind.
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.parse() {
+            Ok(WsMessageKind::Unknown(kind)) => Err(kind),
+            Ok(kind) => Ok(kind),
+            Err(e) => match e {},
+        }
+    }
 }
 
 /// Common request message.
@@ -328,7 +687,8 @@ impl WsMessage {
 /// let msg_data = request.msg_data.unwrap_or_default();
 /// assert_eq!(Some("bar"), msg_data.get("foo").and_then(|v| v.as_str()));
 /// ```
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(not(feature = "redact-debug"), derive(Debug))]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct WsRequest {
     /// Request message identifier: `req`
     pub kind: String,
@@ -378,6 +738,13 @@ impl WsRequest {
     }
 }
 
+/// Short request summary for logging purposes, without the message payload: `req #{id} {msg}`.
+impl Display for WsRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} #{} {}", self.kind, self.id, self.msg)
+    }
+}
+
 impl From<WsRequest> for WsMessage {
     fn from(r: WsRequest) -> Self {
         Self {
@@ -411,7 +778,8 @@ impl From<WsRequest> for WsMessage {
 ///     "code": 200
 /// }), json);
 /// ```
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(not(feature = "redact-debug"), derive(Debug))]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct WsResponse {
     /// Response message identifier: `resp`
     pub kind: String,
@@ -503,6 +871,34 @@ impl WsResponse {
         }
     }
 
+    /// Helper method to create a response message from the result of a request handler.
+    ///
+    /// - `Ok(data)`: response message with the serialized data as `msg_data` payload, see
+    ///   [`WsResponse::new`].
+    /// - `Err((code, msg_data))`: error response message with the given status code, see
+    ///   [`WsResponse::error`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uc_api::ws::{WsResponse, WsResultMsgData};
+    /// let result: Result<(), (u16, WsResultMsgData)> =
+    ///     Err((404, WsResultMsgData::new("NOT_FOUND", "Entity not found")));
+    /// let response = WsResponse::from_result(123, "result", result);
+    /// assert_eq!(404, response.code);
+    /// assert_eq!("result", response.msg);
+    /// ```
+    pub fn from_result<T: serde::Serialize, E: Into<WsResultMsgData>>(
+        req_id: u32,
+        msg: &str,
+        result: Result<T, (u16, E)>,
+    ) -> Self {
+        match result {
+            Ok(data) => Self::new(req_id, msg, data),
+            Err((code, msg_data)) => Self::error(req_id, code, msg_data.into()),
+        }
+    }
+
     /// Helper method to create a 400 "bad request" error response message for a missing field.
     ///
     /// # Examples
@@ -567,6 +963,207 @@ impl WsResponse {
         }
     }
 
+    /// Helper method to create a 401 "unauthorized" error response message with a custom message.
+    ///
+    /// # Examples
+    ///
+    /// Serialize to JSON:
+    /// ```
+    /// use uc_api::ws::WsResponse;
+    /// let response = WsResponse::unauthorized(123, "Authentication required");
+    /// let json = serde_json::to_value(response).unwrap();
+    /// assert_eq!(serde_json::json!({
+    ///     "kind": "resp",
+    ///     "req_id": 123,
+    ///     "msg": "result",
+    ///     "code": 401,
+    ///     "msg_data": {
+    ///         "code": "UNAUTHORIZED",
+    ///         "message": "Authentication required"
+    ///     }
+    /// }), json);
+    ///
+    /// ```
+    pub fn unauthorized(req_id: u32, message: impl Into<String>) -> Self {
+        Self {
+            kind: "resp".into(),
+            req_id,
+            msg: "result".into(),
+            code: 401,
+            msg_data: Some(json!({ "code": "UNAUTHORIZED", "message": message.into() })),
+        }
+    }
+
+    /// Helper method to create a 403 "forbidden" error response message with a custom message.
+    ///
+    /// # Examples
+    ///
+    /// Serialize to JSON:
+    /// ```
+    /// use uc_api::ws::WsResponse;
+    /// let response = WsResponse::forbidden(123, "Operation not allowed");
+    /// let json = serde_json::to_value(response).unwrap();
+    /// assert_eq!(serde_json::json!({
+    ///     "kind": "resp",
+    ///     "req_id": 123,
+    ///     "msg": "result",
+    ///     "code": 403,
+    ///     "msg_data": {
+    ///         "code": "FORBIDDEN",
+    ///         "message": "Operation not allowed"
+    ///     }
+    /// }), json);
+    ///
+    /// ```
+    pub fn forbidden(req_id: u32, message: impl Into<String>) -> Self {
+        Self {
+            kind: "resp".into(),
+            req_id,
+            msg: "result".into(),
+            code: 403,
+            msg_data: Some(json!({ "code": "FORBIDDEN", "message": message.into() })),
+        }
+    }
+
+    /// Helper method to create a 409 "conflict" error response message with a custom message.
+    ///
+    /// # Examples
+    ///
+    /// Serialize to JSON:
+    /// ```
+    /// use uc_api::ws::WsResponse;
+    /// let response = WsResponse::conflict(123, "Resource already exists");
+    /// let json = serde_json::to_value(response).unwrap();
+    /// assert_eq!(serde_json::json!({
+    ///     "kind": "resp",
+    ///     "req_id": 123,
+    ///     "msg": "result",
+    ///     "code": 409,
+    ///     "msg_data": {
+    ///         "code": "CONFLICT",
+    ///         "message": "Resource already exists"
+    ///     }
+    /// }), json);
+    ///
+    /// ```
+    pub fn conflict(req_id: u32, message: impl Into<String>) -> Self {
+        Self {
+            kind: "resp".into(),
+            req_id,
+            msg: "result".into(),
+            code: 409,
+            msg_data: Some(json!({ "code": "CONFLICT", "message": message.into() })),
+        }
+    }
+
+    /// Helper method to create a 500 "internal server error" error response message with a custom message.
+    ///
+    /// # Examples
+    ///
+    /// Serialize to JSON:
+    /// ```
+    /// use uc_api::ws::WsResponse;
+    /// let response = WsResponse::internal_error(123, "Unexpected error");
+    /// let json = serde_json::to_value(response).unwrap();
+    /// assert_eq!(serde_json::json!({
+    ///     "kind": "resp",
+    ///     "req_id": 123,
+    ///     "msg": "result",
+    ///     "code": 500,
+    ///     "msg_data": {
+    ///         "code": "INTERNAL_ERROR",
+    ///         "message": "Unexpected error"
+    ///     }
+    /// }), json);
+    ///
+    /// ```
+    pub fn internal_error(req_id: u32, message: impl Into<String>) -> Self {
+        Self {
+            kind: "resp".into(),
+            req_id,
+            msg: "result".into(),
+            code: 500,
+            msg_data: Some(json!({ "code": "INTERNAL_ERROR", "message": message.into() })),
+        }
+    }
+
+    /// Helper method to create a 503 "service unavailable" error response message with a custom message.
+    ///
+    /// # Examples
+    ///
+    /// Serialize to JSON:
+    /// ```
+    /// use uc_api::ws::WsResponse;
+    /// let response = WsResponse::service_unavailable(123, "Device is offline");
+    /// let json = serde_json::to_value(response).unwrap();
+    /// assert_eq!(serde_json::json!({
+    ///     "kind": "resp",
+    ///     "req_id": 123,
+    ///     "msg": "result",
+    ///     "code": 503,
+    ///     "msg_data": {
+    ///         "code": "SERVICE_UNAVAILABLE",
+    ///         "message": "Device is offline"
+    ///     }
+    /// }), json);
+    ///
+    /// ```
+    pub fn service_unavailable(req_id: u32, message: impl Into<String>) -> Self {
+        Self {
+            kind: "resp".into(),
+            req_id,
+            msg: "result".into(),
+            code: 503,
+            msg_data: Some(json!({ "code": "SERVICE_UNAVAILABLE", "message": message.into() })),
+        }
+    }
+
+    /// Helper method to create a 400 "bad request" error response message for validation errors.
+    ///
+    /// See [`WsResultMsgData::from_validation_errors`] for the `msg_data` payload.
+    pub fn validation_error(req_id: u32, errors: &ValidationErrors) -> Self {
+        Self::error(req_id, 400, WsResultMsgData::from_validation_errors(errors))
+    }
+
+    /// Helper method to create a 429 "too many requests" error response message.
+    ///
+    /// The optional `retry_after` value in seconds informs the client when the request may be
+    /// repeated.
+    ///
+    /// # Examples
+    ///
+    /// Serialize to JSON:
+    /// ```
+    /// use uc_api::ws::WsResponse;
+    /// let response = WsResponse::rate_limited(123, Some(5));
+    /// let json = serde_json::to_value(response).unwrap();
+    /// assert_eq!(serde_json::json!({
+    ///     "kind": "resp",
+    ///     "req_id": 123,
+    ///     "msg": "result",
+    ///     "code": 429,
+    ///     "msg_data": {
+    ///         "code": "TOO_MANY_REQUESTS",
+    ///         "message": "Rate limit exceeded",
+    ///         "retry_after": 5
+    ///     }
+    /// }), json);
+    ///
+    /// ```
+    pub fn rate_limited(req_id: u32, retry_after_secs: Option<u32>) -> Self {
+        let mut msg_data = json!({ "code": "TOO_MANY_REQUESTS", "message": "Rate limit exceeded" });
+        if let Some(retry_after) = retry_after_secs {
+            msg_data["retry_after"] = retry_after.into();
+        }
+        Self {
+            kind: "resp".into(),
+            req_id,
+            msg: "result".into(),
+            code: 429,
+            msg_data: Some(msg_data),
+        }
+    }
+
     /// Helper method to create a simple response message without `msg_data` payload.
     ///
     /// # Examples
@@ -593,6 +1190,35 @@ impl WsResponse {
             msg_data: None,
         }
     }
+
+    /// Check if the response has a 2xx success code.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.code)
+    }
+
+    /// Check if the response has a 4xx client error code.
+    pub fn is_client_error(&self) -> bool {
+        (400..500).contains(&self.code)
+    }
+
+    /// Check if the response has a 5xx server error code.
+    pub fn is_server_error(&self) -> bool {
+        (500..600).contains(&self.code)
+    }
+}
+
+/// Short response summary for logging purposes, without the message payload:
+/// `resp #{req_id} {code} {msg}`.
+///
+/// The same format as the [`WsMessage`] summary of a response message.
+impl Display for WsResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} #{} {} {}",
+            self.kind, self.req_id, self.code, self.msg
+        )
+    }
 }
 
 impl From<WsResponse> for WsMessage {
@@ -608,6 +1234,213 @@ impl From<WsResponse> for WsMessage {
     }
 }
 
+/// Common event message.
+///
+/// # Examples
+///
+/// Deserialize from JSON:
+/// ```
+/// use uc_api::ws::{EventCategory, WsEvent};
+/// let json = serde_json::json!({
+///     "kind": "event",
+///     "msg": "entity_change",
+///     "cat": "ENTITY",
+///     "ts": "2024-01-02T03:04:05Z",
+///     "msg_data": {
+///         "foo": "bar"
+///     }
+/// });
+/// let event: WsEvent = serde_json::from_value(json).expect("Invalid json message");
+/// assert_eq!("event", &event.kind);
+/// assert_eq!("entity_change", &event.msg);
+/// assert_eq!(EventCategory::Entity, event.cat);
+/// let ts = event.ts.expect("missing ts");
+/// assert_eq!("2024-01-02T03:04:05Z", ts.to_rfc3339_opts(chrono::SecondsFormat::Secs, true));
+/// ```
+#[cfg_attr(not(feature = "redact-debug"), derive(Debug))]
+#[derive(Clone, Deserialize, Serialize)]
+pub struct WsEvent {
+    /// Event message identifier: `event`
+    pub kind: String,
+    /// One of the defined API event message types.
+    pub msg: String,
+    /// Category of the event. Defaults to `Device` if not set.
+    #[serde(default)]
+    pub cat: EventCategory,
+    /// Optional timestamp when the event was generated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<DateTime<Utc>>,
+    /// Message specific payload.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msg_data: Option<Value>,
+}
+
+impl WsEvent {
+    /// Helper method to create an event message with the current timestamp.
+    ///
+    /// # Examples
+    ///
+    /// Serialize to JSON:
+    /// ```
+    /// use serde_json::json;
+    /// use uc_api::ws::{EventCategory, WsEvent};
+    /// let event = WsEvent::new("test_event", EventCategory::Remote, json!({ "foo": "bar" }));
+    /// let mut json = serde_json::to_value(event).unwrap();
+    /// assert!(json.as_object_mut().and_then(|o| o.remove("ts")).is_some());
+    /// assert_eq!(json!({
+    ///     "kind": "event",
+    ///     "msg": "test_event",
+    ///     "cat": "REMOTE",
+    ///     "msg_data": {
+    ///         "foo": "bar"
+    ///     }
+    /// }), json);
+    /// ```
+    pub fn new(msg: impl Into<String>, cat: EventCategory, msg_data: Value) -> Self {
+        Self {
+            kind: "event".into(),
+            msg: msg.into(),
+            cat,
+            ts: Some(Utc::now()),
+            msg_data: Some(msg_data),
+        }
+    }
+}
+
+/// Short event summary for logging purposes, without the message payload: `event {msg} [{cat}]`.
+impl Display for WsEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} [{}]", self.kind, self.msg, self.cat)
+    }
+}
+
+impl From<WsEvent> for WsMessage {
+    fn from(e: WsEvent) -> Self {
+        Self {
+            kind: Some(e.kind),
+            msg: Some(e.msg),
+            cat: Some(e.cat),
+            ts: e.ts,
+            msg_data: e.msg_data,
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<WsMessage> for WsEvent {
+    type Error = WsMessage;
+
+    /// Convert an event message into a typed event.
+    ///
+    /// The category defaults to `Device` if not set.
+    /// Non-standard fields are dropped.
+    ///
+    /// # Errors
+    ///
+    /// The original message is returned if it's not an `event` message or the `msg` field is
+    /// missing.
+    fn try_from(msg: WsMessage) -> Result<Self, Self::Error> {
+        match (msg.kind.as_deref(), &msg.msg) {
+            (Some("event"), Some(_)) => Ok(WsEvent {
+                kind: "event".into(),
+                cat: msg.category(),
+                ts: msg.ts,
+                msg: msg.msg.unwrap_or_default(),
+                msg_data: msg.msg_data,
+            }),
+            _ => Err(msg),
+        }
+    }
+}
+
+#[cfg(feature = "redact-debug")]
+mod redact {
+    use super::{WsEvent, WsMessage, WsRequest, WsResponse};
+    use serde_json::Value;
+    use std::collections::HashMap;
+    use std::fmt::{Debug, Formatter, Result};
+
+    /// Object keys containing sensitive data which must not be logged.
+    const SENSITIVE_KEYS: [&str; 3] = ["access_token", "refresh_token", "token"];
+    const REDACTED: &str = "<REDACTED>";
+
+    /// Create a copy of the JSON value with all sensitive fields replaced.
+    fn redact_value(value: &Value) -> Value {
+        match value {
+            Value::Object(map) => Value::Object(
+                map.iter()
+                    .map(|(k, v)| (k.clone(), redact_field(k, v)))
+                    .collect(),
+            ),
+            Value::Array(array) => Value::Array(array.iter().map(redact_value).collect()),
+            v => v.clone(),
+        }
+    }
+
+    fn redact_field(key: &str, value: &Value) -> Value {
+        if SENSITIVE_KEYS.contains(&key) {
+            Value::String(REDACTED.into())
+        } else {
+            redact_value(value)
+        }
+    }
+
+    fn redact_map(map: &HashMap<String, Value>) -> HashMap<&String, Value> {
+        map.iter().map(|(k, v)| (k, redact_field(k, v))).collect()
+    }
+
+    impl Debug for WsMessage {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            f.debug_struct("WsMessage")
+                .field("kind", &self.kind)
+                .field("id", &self.id)
+                .field("req_id", &self.req_id)
+                .field("msg", &self.msg)
+                .field("code", &self.code)
+                .field("cat", &self.cat)
+                .field("ts", &self.ts)
+                .field("msg_data", &self.msg_data.as_ref().map(redact_value))
+                .field("extra", &redact_map(&self.extra))
+                .finish()
+        }
+    }
+
+    impl Debug for WsRequest {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            f.debug_struct("WsRequest")
+                .field("kind", &self.kind)
+                .field("id", &self.id)
+                .field("msg", &self.msg)
+                .field("msg_data", &self.msg_data.as_ref().map(redact_value))
+                .finish()
+        }
+    }
+
+    impl Debug for WsEvent {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            f.debug_struct("WsEvent")
+                .field("kind", &self.kind)
+                .field("msg", &self.msg)
+                .field("cat", &self.cat)
+                .field("ts", &self.ts)
+                .field("msg_data", &self.msg_data.as_ref().map(redact_value))
+                .finish()
+        }
+    }
+
+    impl Debug for WsResponse {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            f.debug_struct("WsResponse")
+                .field("kind", &self.kind)
+                .field("req_id", &self.req_id)
+                .field("msg", &self.msg)
+                .field("code", &self.code)
+                .field("msg_data", &self.msg_data.as_ref().map(redact_value))
+                .finish()
+        }
+    }
+}
+
 /// Default payload data of `result` response message in `msg_data` property.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WsResultMsgData {
@@ -622,15 +1455,48 @@ impl WsResultMsgData {
             message: message.into(),
         }
     }
+
+    /// Create a `VALIDATION_ERROR` result from model validation errors.
+    ///
+    /// The message lists every invalid field with its error message, one field per line.
+    pub fn from_validation_errors(errors: &ValidationErrors) -> Self {
+        Self::new("VALIDATION_ERROR", errors.to_string())
+    }
 }
 
 /// Event message categories.
 ///
-/// Variants will be serialized in `SCREAMING_SNAKE_CASE`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+/// Variants will be serialized in `SCREAMING_SNAKE_CASE`. Parsing from a string with `FromStr` is
+/// case-insensitive.
+///
+/// The default category is `Device`, which is assumed for event messages without a category.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+/// use uc_api::ws::EventCategory;
+/// assert_eq!(Ok(EventCategory::Device), EventCategory::from_str("DEVICE"));
+/// assert_eq!(Ok(EventCategory::Device), EventCategory::from_str("device"));
+/// ```
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Deserialize,
+    Serialize,
+    AsRefStr,
+    Display,
+    EnumString,
+)] // strum_macros
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE", ascii_case_insensitive)]
 pub enum EventCategory {
     /// Device specific events like integration driver status changes
+    #[default]
     Device,
     /// Entity change events
     Entity,
@@ -638,6 +1504,42 @@ pub enum EventCategory {
     Remote,
     /// UI change events
     Ui,
+    /// Driver and integration configuration change events
+    Config,
+    /// System events like firmware updates, connectivity changes and reboots
+    System,
+    /// User profile and permission change events
+    User,
+}
+
+impl EventCategory {
+    /// Human-readable name of the category, e.g. for displaying in a user interface.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uc_api::ws::EventCategory;
+    /// assert_eq!("Configuration", EventCategory::Config.display_name());
+    /// assert_eq!("User interface", EventCategory::Ui.display_name());
+    /// ```
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            EventCategory::Device => "Device",
+            EventCategory::Entity => "Entity",
+            EventCategory::Remote => "Remote",
+            EventCategory::Ui => "User interface",
+            EventCategory::Config => "Configuration",
+            EventCategory::System => "System",
+            EventCategory::User => "User",
+        }
+    }
+
+    /// Case-insensitive parsing of an event category.
+    ///
+    /// Returns `None` if the value is not a known category.
+    pub fn try_from_str(value: &str) -> Option<Self> {
+        value.parse().ok()
+    }
 }
 
 #[cfg(test)]
@@ -681,4 +1583,288 @@ mod tests {
             json
         );
     }
+
+    #[test]
+    fn response_code_ranges() {
+        // (code, success, client error, server error)
+        let codes = [
+            (199, false, false, false),
+            (200, true, false, false),
+            (299, true, false, false),
+            (300, false, false, false),
+            (399, false, false, false),
+            (400, false, true, false),
+            (499, false, true, false),
+            (500, false, false, true),
+            (599, false, false, true),
+            (600, false, false, false),
+        ];
+
+        for (code, success, client_error, server_error) in codes {
+            let response = WsResponse::result(1, code);
+            assert_eq!(success, response.is_success(), "{code}");
+            assert_eq!(client_error, response.is_client_error(), "{code}");
+            assert_eq!(server_error, response.is_server_error(), "{code}");
+
+            let msg = WsMessage::from(response);
+            assert_eq!(success, msg.is_success(), "{code}");
+            assert_eq!(client_error, msg.is_client_error(), "{code}");
+            assert_eq!(server_error, msg.is_server_error(), "{code}");
+        }
+
+        let msg = WsMessage::simple_request(1, "get_version");
+        assert!(!msg.is_success());
+        assert!(!msg.is_client_error());
+        assert!(!msg.is_server_error());
+    }
+
+    #[test]
+    fn message_into_request() {
+        let msg = WsMessage::request(1, "foo", json!({ "foo": "bar" })).unwrap();
+        let request = msg.try_into_request().expect("valid request");
+        assert_eq!("req", request.kind);
+        assert_eq!(1, request.id);
+        assert_eq!("foo", request.msg);
+        assert_eq!(Some(json!({ "foo": "bar" })), request.msg_data);
+
+        // wrong kind
+        let msg = WsMessage::response(1, "foo", json!({}));
+        assert_eq!(Some(1), msg.clone().try_into_request().unwrap_err().req_id);
+
+        // missing fields
+        for json in [
+            json!({ "kind": "req", "msg": "foo" }),
+            json!({ "kind": "req", "id": 1 }),
+            json!({ "id": 1, "msg": "foo" }),
+        ] {
+            let msg: WsMessage = serde_json::from_value(json.clone()).unwrap();
+            let msg = msg.try_into_request().unwrap_err();
+            assert_eq!(json, serde_json::to_value(msg).unwrap());
+        }
+    }
+
+    #[test]
+    fn message_into_response() {
+        let msg = WsMessage::from(WsResponse::not_found(1, "foobar"));
+        let response = msg.try_into_response().expect("valid response");
+        assert_eq!("resp", response.kind);
+        assert_eq!(1, response.req_id);
+        assert_eq!("result", response.msg);
+        assert_eq!(404, response.code);
+        assert_eq!(
+            Some(json!({ "code": "NOT_FOUND", "message": "foobar" })),
+            response.msg_data
+        );
+
+        // wrong kind
+        let msg = WsMessage::simple_request(1, "foo");
+        assert_eq!(Some(1), msg.try_into_response().unwrap_err().id);
+
+        // missing fields
+        for json in [
+            json!({ "kind": "resp", "msg": "foo", "code": 200 }),
+            json!({ "kind": "resp", "req_id": 1, "code": 200 }),
+            json!({ "kind": "resp", "req_id": 1, "msg": "foo" }),
+        ] {
+            let msg: WsMessage = serde_json::from_value(json.clone()).unwrap();
+            let msg = msg.try_into_response().unwrap_err();
+            assert_eq!(json, serde_json::to_value(msg).unwrap());
+        }
+    }
+
+    #[test]
+    fn event_to_message_conversion() {
+        let ts = "2024-01-02T03:04:05.678Z".parse().unwrap();
+        let event = WsEvent {
+            ts: Some(ts),
+            ..WsEvent::new(
+                "entity_change",
+                EventCategory::Entity,
+                json!({ "foo": "bar" }),
+            )
+        };
+
+        let msg = WsMessage::from(event);
+        assert_eq!(Some(ts), msg.ts);
+        assert_eq!(
+            json!({
+                "kind": "event",
+                "msg": "entity_change",
+                "cat": "ENTITY",
+                "ts": "2024-01-02T03:04:05.678Z",
+                "msg_data": { "foo": "bar" }
+            }),
+            serde_json::to_value(msg).unwrap()
+        );
+    }
+
+    #[test]
+    fn message_into_event() {
+        let msg: WsMessage = serde_json::from_value(json!({
+            "kind": "event",
+            "msg": "entity_change",
+            "cat": "ENTITY",
+            "ts": "2024-01-02T03:04:05.678Z",
+            "msg_data": { "foo": "bar" }
+        }))
+        .unwrap();
+        let ts = msg.ts;
+
+        let event = msg.try_into_event().expect("valid event");
+        assert_eq!("event", event.kind);
+        assert_eq!("entity_change", event.msg);
+        assert_eq!(EventCategory::Entity, event.cat);
+        assert_eq!(ts, event.ts);
+        assert_eq!(Some(json!({ "foo": "bar" })), event.msg_data);
+
+        // category and timestamp are optional
+        let event = WsEvent::try_from(WsMessage::event("foo", None, json!({})).without_ts())
+            .expect("valid event");
+        assert_eq!(EventCategory::Device, event.cat);
+        assert_eq!(None, event.ts);
+        assert_eq!(None, WsMessage::from(event).ts);
+
+        // wrong kind
+        let msg = WsMessage::simple_request(1, "foo");
+        assert_eq!(Some(1), msg.try_into_event().unwrap_err().id);
+
+        // missing msg
+        let msg: WsMessage = serde_json::from_value(json!({ "kind": "event" })).unwrap();
+        assert!(WsEvent::try_from(msg).is_err());
+    }
+
+    #[test]
+    fn event_category_serialization() {
+        for (cat, value) in [
+            (EventCategory::Config, "CONFIG"),
+            (EventCategory::System, "SYSTEM"),
+            (EventCategory::User, "USER"),
+        ] {
+            assert_eq!(json!(value), serde_json::to_value(cat).unwrap());
+            assert_eq!(cat, serde_json::from_value(json!(value)).unwrap());
+            assert_eq!(
+                Some(cat),
+                EventCategory::try_from_str(&value.to_lowercase())
+            );
+            assert_eq!(value, cat.to_string());
+        }
+    }
+
+    #[test]
+    fn validation_error_response() {
+        use crate::intg::IntegrationDriverUpdate;
+        use validator::Validate;
+
+        let update: IntegrationDriverUpdate = serde_json::from_value(
+            json!({ "driver_url": "http://localhost:9090", "version": "1.0.0" }),
+        )
+        .unwrap();
+        let errors = update.validate().expect_err("invalid driver_url");
+
+        let response = WsResponse::validation_error(123, &errors);
+        assert_eq!(123, response.req_id);
+        assert_eq!(400, response.code);
+        let msg_data: WsResultMsgData =
+            serde_json::from_value(response.msg_data.expect("missing msg_data")).unwrap();
+        assert_eq!("VALIDATION_ERROR", msg_data.code);
+        assert!(
+            msg_data.message.contains("driver_url"),
+            "{}",
+            msg_data.message
+        );
+        assert!(
+            !msg_data.message.contains("version"),
+            "{}",
+            msg_data.message
+        );
+    }
+
+    #[test]
+    fn message_serde_round_trip() {
+        let messages = [
+            // minimal message
+            json!({ "kind": "req" }),
+            // request with msg_data
+            json!({ "kind": "req", "id": 1, "msg": "get_version", "msg_data": { "foo": "bar" } }),
+            // response with code and msg_data
+            json!({
+                "kind": "resp",
+                "req_id": 1,
+                "msg": "result",
+                "code": 404,
+                "msg_data": { "code": "NOT_FOUND", "message": "foobar" }
+            }),
+            // event with timestamp and category
+            json!({
+                "kind": "event",
+                "msg": "entity_change",
+                "cat": "ENTITY",
+                "ts": "2024-01-02T03:04:05Z",
+                "msg_data": { "entity_id": "light1" }
+            }),
+            // message with extra fields
+            json!({
+                "kind": "req",
+                "id": 2,
+                "msg": "custom",
+                "x_trace": "abc",
+                "x_nested": { "foo": [1, 2, 3] }
+            }),
+        ];
+
+        for json in messages {
+            let msg: WsMessage = serde_json::from_value(json.clone()).expect("Invalid json");
+            assert_eq!(json, serde_json::to_value(msg).unwrap());
+        }
+    }
+
+    #[test]
+    fn message_extra_fields_are_not_mixed_with_known_fields() {
+        let msg: WsMessage = serde_json::from_value(
+            json!({ "kind": "resp", "req_id": 1, "code": 200, "x_trace": "abc" }),
+        )
+        .expect("Invalid json");
+
+        assert_eq!(Some(1), msg.req_id);
+        assert_eq!(Some(200), msg.code);
+        assert_eq!(1, msg.extra.len());
+        assert_eq!(Some(&json!("abc")), msg.extra.get("x_trace"));
+    }
+
+    #[test]
+    fn display_message_summary() {
+        let request = WsRequest::new(123, "test_request", json!({ "token": "secret" }))
+            .expect("bug: may not fail");
+        assert_eq!("req #123 test_request", request.to_string());
+        assert_eq!(
+            "req #123 test_request",
+            WsMessage::from(request).to_string()
+        );
+
+        let response = WsResponse::not_found(123, "secret");
+        assert_eq!("resp #123 404 result", response.to_string());
+        assert_eq!(
+            "resp #123 404 result",
+            WsMessage::from(response).to_string()
+        );
+
+        assert_eq!("unknown", WsMessage::default().to_string());
+    }
+
+    #[cfg(feature = "redact-debug")]
+    #[test]
+    fn debug_output_redacts_tokens() {
+        let response = WsResponse::new(
+            123,
+            "test_result",
+            json!({ "token_id": "foo", "token": { "access_token": "secret1", "refresh_token": "secret2" } }),
+        );
+        let msg = WsMessage::from(response.clone());
+
+        for output in [format!("{response:?}"), format!("{msg:?}")] {
+            assert!(output.contains("foo"), "{output}");
+            assert!(output.contains("<REDACTED>"), "{output}");
+            assert!(!output.contains("secret"), "{output}");
+        }
+    }
 }